@@ -0,0 +1,238 @@
+use anyhow::{Context, Result, bail};
+use clap::{ArgGroup, Parser};
+use common::answers::{Answers, Verdict};
+use common::bench::{self, Bench, BenchArgs};
+use common::input::{self, InputSource};
+use common::output::{self, Format};
+use common::{Part, Run, Solution};
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Type-erased [`Solution`], so that every day can be listed together.
+trait Day {
+    fn day(&self) -> u8;
+
+    fn run(&self, input: Vec<String>, parts: &[Part]) -> Result<Run>;
+
+    fn bench(&self, input: &[String], parts: &[Part], iterations: usize) -> Result<Vec<Bench>>;
+}
+
+impl<S: Solution> Day for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: Vec<String>, parts: &[Part]) -> Result<Run> {
+        common::run(self, input, parts)
+    }
+
+    fn bench(&self, input: &[String], parts: &[Part], iterations: usize) -> Result<Vec<Bench>> {
+        bench::bench(self, input, parts, iterations)
+    }
+}
+
+fn days() -> Vec<Box<dyn Day>> {
+    vec![
+        Box::new(Day01::default()),
+        Box::new(Day02::default()),
+        Box::new(Day03),
+        Box::new(Day04),
+        Box::new(Day05),
+        Box::new(Day06),
+        Box::new(Day07),
+        Box::new(Day08::default()),
+        Box::new(Day09),
+        Box::new(Day10),
+        Box::new(Day11),
+        Box::new(Day12),
+    ]
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Run Advent of Code 2025 solutions")]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct Args {
+    /// Run a single day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Run every implemented day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Only solve one part of each day
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Directory containing puzzle inputs named `dayNN.txt`
+    #[arg(short, long, default_value = "input")]
+    input_dir: PathBuf,
+
+    /// How to print answers
+    #[arg(short, long, value_enum, default_value_t)]
+    output: Format,
+
+    /// Increase logging verbosity
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Check answers against the known answers file instead of printing them
+    #[arg(long, conflicts_with = "bench")]
+    verify: bool,
+
+    /// Known answers as `day,part,answer` lines [default: <INPUT_DIR>/answers.csv]
+    #[arg(long, value_name = "PATH", requires = "verify")]
+    answers: Option<PathBuf>,
+
+    #[command(flatten)]
+    bench: BenchArgs,
+}
+
+fn read_input(input_dir: &Path, day: &dyn Day) -> Result<Vec<String>> {
+    InputSource::File(input_dir.join(input::file_name(day.day()))).read()
+}
+
+fn print_table(runs: &[Run]) {
+    println!(
+        "{:>3}  {:>20}  {:>20}  {:>12}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+
+    for run in runs {
+        let answer = |part| {
+            run.answer(part)
+                .map_or_else(|| "-".to_string(), ToString::to_string)
+        };
+
+        println!(
+            "{:>3}  {:>20}  {:>20}  {:>12}",
+            format!("{:02}", run.day),
+            answer(Part::One),
+            answer(Part::Two),
+            format!("{:.3?}", run.elapsed())
+        );
+    }
+
+    let total = runs.iter().map(Run::elapsed).sum::<Duration>();
+    println!(
+        "{:>3}  {:>20}  {:>20}  {:>12}",
+        "",
+        "",
+        "",
+        format!("{total:.3?}")
+    );
+}
+
+/// Solves each day which has both known answers and an input, printing a verdict per part and
+/// failing if any answer doesn't match.
+fn verify(
+    days: &[Box<dyn Day>],
+    parts: &[Part],
+    input_dir: &Path,
+    answers: &Answers,
+) -> Result<()> {
+    let mut mismatches = 0;
+
+    for day in days.iter() {
+        let path = input_dir.join(input::file_name(day.day()));
+        if !answers.has_day(day.day()) {
+            println!("{:02}  skipped (no known answers)", day.day());
+            continue;
+        }
+        if !path.exists() {
+            println!("{:02}  skipped (no input at {path:?})", day.day());
+            continue;
+        }
+
+        let run = day
+            .run(InputSource::File(path).read()?, parts)
+            .with_context(|| format!("Failed to solve day {}", day.day()))?;
+
+        for part in run.parts.iter() {
+            let verdict = answers.verify(run.day, part.part, &part.answer);
+            if matches!(verdict, Verdict::Incorrect { .. }) {
+                mismatches += 1;
+            }
+
+            println!(
+                "{:02}  part {}  {:>20}  {verdict}",
+                run.day,
+                part.part,
+                part.answer.to_string()
+            );
+        }
+    }
+
+    if mismatches > 0 {
+        bail!("{mismatches} answer(s) did not match");
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    common::cli::init_logger(args.verbose);
+
+    let mut days = days();
+    if let Some(day) = args.day {
+        days.retain(|d| d.day() == day);
+        if days.is_empty() {
+            bail!("Day {day} is not implemented");
+        }
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if args.verify {
+        let answers = args
+            .answers
+            .unwrap_or_else(|| args.input_dir.join("answers.csv"));
+        return verify(&days, &parts, &args.input_dir, &Answers::load(&answers)?);
+    }
+
+    if let Some(iterations) = args.bench.bench {
+        let mut benches = Vec::new();
+        for day in days.iter() {
+            let input = read_input(&args.input_dir, day.as_ref())?;
+            benches.extend(
+                day.bench(&input, &parts, iterations)
+                    .with_context(|| format!("Failed to benchmark day {}", day.day()))?,
+            );
+        }
+
+        return bench::report(&args.bench, &benches);
+    }
+
+    let mut runs = Vec::new();
+    for day in days.iter() {
+        let input = read_input(&args.input_dir, day.as_ref())?;
+        runs.push(
+            day.run(input, &parts)
+                .with_context(|| format!("Failed to solve day {}", day.day()))?,
+        );
+    }
+
+    match args.output {
+        Format::Text => print_table(&runs),
+        Format::Json => output::print_json(&runs)?,
+    }
+
+    Ok(())
+}
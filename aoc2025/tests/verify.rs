@@ -0,0 +1,29 @@
+//! Checks every day against its real input, using the known answers in `input/answers.csv`.
+//!
+//! Puzzle inputs and answers aren't committed, so this does nothing unless they have been added
+//! locally.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn known_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    if !root.join("input/answers.csv").exists() {
+        eprintln!("Skipping: no input/answers.csv");
+        return;
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc2025"))
+        .current_dir(&root)
+        .args(["--all", "--verify"])
+        .output()
+        .expect("Failed to run aoc2025");
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
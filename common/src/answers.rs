@@ -0,0 +1,139 @@
+use crate::{Answer, Part};
+use anyhow::{Context, Result, anyhow, bail};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Known answers for real puzzle inputs, one `day,part,answer` per line. Blank lines and lines
+/// starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    /// Reads an answers file from `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read answers from {path:?}"))?
+            .parse()
+            .with_context(|| format!("Failed to parse answers from {path:?}"))
+    }
+
+    /// The recorded answer for `day` and `part`, if any.
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Whether any answer is recorded for `day`.
+    pub fn has_day(&self, day: u8) -> bool {
+        self.0.keys().any(|(d, _)| *d == day)
+    }
+
+    /// Compares `answer` to the recorded answer for `day` and `part`.
+    pub fn verify(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Skipped,
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ',').map(str::trim);
+            let (Some(day), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                bail!("line {}: expected `day,part,answer`, got {line:?}", i + 1);
+            };
+
+            let day = day
+                .parse::<u8>()
+                .map_err(|e| anyhow!("line {}: invalid day {day:?}: {e}", i + 1))?;
+            let part = part
+                .parse::<Part>()
+                .with_context(|| format!("line {}", i + 1))?;
+
+            if answers.0.insert((day, part), answer.to_string()).is_some() {
+                bail!("line {}: duplicate answer for day {day} part {part}", i + 1);
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// The outcome of checking an answer against the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Skipped,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Incorrect { expected } => write!(f, "MISMATCH (expected {expected})"),
+            Verdict::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<()> {
+        let answers: Answers = "# day,part,answer\n1,1,1234\n\n3, 2, 3121910778619\n".parse()?;
+
+        assert_eq!(Some("1234"), answers.get(1, Part::One));
+        assert_eq!(None, answers.get(1, Part::Two));
+        assert_eq!(Some("3121910778619"), answers.get(3, Part::Two));
+        assert!(answers.has_day(3));
+        assert!(!answers.has_day(2));
+
+        assert!("1,1,5\n1,1,6".parse::<Answers>().is_err());
+        assert!("1,3,5".parse::<Answers>().is_err());
+        assert!("1,1".parse::<Answers>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify() -> Result<()> {
+        let answers: Answers = "1,1,3\n1,2,6".parse()?;
+
+        assert_eq!(
+            Verdict::Correct,
+            answers.verify(1, Part::One, &Answer::from(3usize))
+        );
+        assert_eq!(
+            Verdict::Incorrect {
+                expected: "6".to_string()
+            },
+            answers.verify(1, Part::Two, &Answer::from(7usize))
+        );
+        assert_eq!(
+            Verdict::Skipped,
+            answers.verify(2, Part::One, &Answer::from(7usize))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{Part, Solution, output, warnings};
+use anyhow::Result;
+use log::info;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Command line options for benchmarking.
+#[derive(Debug, Default, clap::Args)]
+pub struct BenchArgs {
+    /// Benchmark parsing and each part instead of printing answers
+    #[arg(long, value_name = "ITERATIONS", num_args = 0..=1, default_missing_value = "100")]
+    pub bench: Option<usize>,
+
+    /// Also write benchmark results as CSV, or `-` for stdout
+    #[arg(long, value_name = "PATH", requires = "bench")]
+    pub bench_output: Option<PathBuf>,
+}
+
+/// A separately timed step of solving a puzzle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// Summary statistics over repeated timings of one stage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let iterations = samples.len();
+        let median = if iterations.is_multiple_of(2) {
+            (samples[iterations / 2 - 1] + samples[iterations / 2]) / 2
+        } else {
+            samples[iterations / 2]
+        };
+
+        Self {
+            iterations,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / iterations as u32,
+        }
+    }
+}
+
+/// The timings of one stage of one day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bench {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Times `iterations` calls to `f`, each given a fresh value from `setup`, which isn't timed. Any
+/// warnings are logged for the first call only, and aren't kept for reporting.
+fn time<I, T>(
+    iterations: usize,
+    mut setup: impl FnMut() -> I,
+    mut f: impl FnMut(I) -> Result<T>,
+) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(iterations);
+
+    let mut sample = || -> Result<()> {
+        let value = setup();
+
+        let start = Instant::now();
+        let result = f(value)?;
+        samples.push(start.elapsed());
+
+        // Don't count dropping the result
+        drop(result);
+        Ok(())
+    };
+
+    sample()?;
+    warnings::take();
+    warnings::quietly(|| (1..iterations).try_for_each(|_| sample()))?;
+
+    Ok(Stats::from_samples(samples))
+}
+
+/// Times parsing `input` and solving each of `parts`, `iterations` times each.
+pub fn bench<S: Solution>(
+    solution: &S,
+    input: &[String],
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Bench>> {
+    anyhow::ensure!(iterations > 0, "Must benchmark at least one iteration");
+
+    let mut benches = Vec::with_capacity(parts.len() + 1);
+
+    let stats = time(iterations, || input.to_vec(), |input| solution.parse(input))?;
+    info!("Day {} parse: {stats:?}", S::DAY);
+    benches.push(Bench {
+        day: S::DAY,
+        stage: Stage::Parse,
+        stats,
+    });
+
+    let parsed = solution.parse(input.to_vec())?;
+    for &part in parts {
+        let stats = time(iterations, || (), |()| solution.solve(&parsed, part))?;
+        info!("Day {} part {part}: {stats:?}", S::DAY);
+        benches.push(Bench {
+            day: S::DAY,
+            stage: Stage::Solve(part),
+            stats,
+        });
+    }
+
+    Ok(benches)
+}
+
+/// Prints benchmark results as a table.
+pub fn print_table(benches: &[Bench]) {
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>12}  {:>12}  {:>12}",
+        "Day", "Stage", "Iterations", "Min", "Median", "Mean"
+    );
+
+    for bench in benches {
+        println!(
+            "{:>3}  {:<6}  {:>10}  {:>12}  {:>12}  {:>12}",
+            format!("{:02}", bench.day),
+            bench.stage.to_string(),
+            bench.stats.iterations,
+            format!("{:.3?}", bench.stats.min),
+            format!("{:.3?}", bench.stats.median),
+            format!("{:.3?}", bench.stats.mean),
+        );
+    }
+}
+
+/// Writes benchmark results as CSV, with durations in nanoseconds.
+pub fn write_csv<W: Write>(mut writer: W, benches: &[Bench]) -> Result<()> {
+    writeln!(writer, "day,stage,iterations,min_ns,median_ns,mean_ns")?;
+
+    for bench in benches {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{}",
+            bench.day,
+            bench.stage,
+            bench.stats.iterations,
+            bench.stats.min.as_nanos(),
+            bench.stats.median.as_nanos(),
+            bench.stats.mean.as_nanos()
+        )?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints `benches` as a table, unless the CSV output requested by `args` is going to stdout.
+pub fn report(args: &BenchArgs, benches: &[Bench]) -> Result<()> {
+    match &args.bench_output {
+        Some(path) if path == Path::new("-") => {}
+        _ => print_table(benches),
+    }
+
+    if let Some(path) = &args.bench_output {
+        write_csv(output::create(path)?, benches)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros).to_vec();
+
+        let stats = Stats::from_samples(samples);
+        assert_eq!(4, stats.iterations);
+        assert_eq!(Duration::from_micros(1), stats.min);
+        assert_eq!(Duration::from_nanos(2500), stats.median);
+        assert_eq!(Duration::from_nanos(2500), stats.mean);
+    }
+
+    #[test]
+    fn time_warnings() -> Result<()> {
+        warnings::take();
+
+        let mut calls = 0;
+        let stats = time(
+            5,
+            || (),
+            |()| {
+                calls += 1;
+                warnings::warn("repeated");
+                Ok(())
+            },
+        )?;
+
+        assert_eq!(5, stats.iterations);
+        assert_eq!(5, calls);
+        assert!(warnings::take().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_setup() -> Result<()> {
+        // Each value is set up just before it's used, rather than all of them up front
+        let (mut set_up, mut used) = (0, 0);
+        time(
+            3,
+            || {
+                set_up += 1;
+                set_up
+            },
+            |value| {
+                used += 1;
+                assert_eq!(used, value);
+                Ok(())
+            },
+        )?;
+        assert_eq!(3, used);
+
+        Ok(())
+    }
+
+    #[test]
+    fn csv() -> Result<()> {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(10)]);
+        let benches = [
+            Bench {
+                day: 3,
+                stage: Stage::Parse,
+                stats,
+            },
+            Bench {
+                day: 3,
+                stage: Stage::Solve(Part::Two),
+                stats,
+            },
+        ];
+
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &benches)?;
+
+        assert_eq!(
+            "day,stage,iterations,min_ns,median_ns,mean_ns\n3,parse,1,10,10,10\n3,part2,1,10,10,10\n",
+            String::from_utf8(csv)?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::bench::{self, BenchArgs};
+use crate::input::InputSource;
+use crate::output::{self, Format};
+use crate::{Part, Run, Solution};
+use anyhow::Result;
+use clap::{ArgAction, Parser};
+use log::LevelFilter;
+
+/// Command line options shared by every day's binary.
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Puzzle input file, or `-` to read from stdin [default: input/dayNN.txt]
+    #[arg(short, long)]
+    pub input: Option<InputSource>,
+
+    /// Only solve one part
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// How to print answers
+    #[arg(short, long, value_enum, default_value_t)]
+    pub output: Format,
+
+    /// Increase logging verbosity
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    #[command(flatten)]
+    pub bench: BenchArgs,
+}
+
+impl Args {
+    /// The parts selected by `--part`, or both.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Solve an Advent of Code 2025 puzzle")]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+}
+
+/// Sets up logging, with `verbose` raising the level from warnings through to tracing.
+pub fn init_logger(verbose: u8) {
+    env_logger::Builder::new()
+        .filter_level(match verbose {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        })
+        .init();
+}
+
+/// Entry point for a day's binary which has no options of its own.
+pub fn main<S: Solution + Default>() -> Result<()> {
+    run(&S::default(), Cli::parse().args)
+}
+
+/// Reads the input selected by `args`, then solves and prints each selected part, or benchmarks
+/// them if requested.
+pub fn run<S: Solution>(solution: &S, args: Args) -> Result<()> {
+    init_logger(args.verbose);
+
+    let input = read_input::<S>(&args)?;
+    solve(solution, &args, input)
+}
+
+/// The input selected by `args`, defaulting to the day's input file.
+pub fn input_source<S: Solution>(args: &Args) -> InputSource {
+    args.input
+        .clone()
+        .unwrap_or_else(|| InputSource::for_day(S::DAY))
+}
+
+/// Reads the input selected by `args`, defaulting to the day's input file.
+pub fn read_input<S: Solution>(args: &Args) -> Result<Vec<String>> {
+    input_source::<S>(args).read()
+}
+
+/// Solves and prints each part selected by `args` for already read `input`, or benchmarks them
+/// if requested.
+pub fn solve<S: Solution>(solution: &S, args: &Args, input: Vec<String>) -> Result<()> {
+    solve_inspecting(solution, args, input, |_| Ok(()))
+}
+
+/// Like [`solve`], but passes the parsed input to `inspect` before solving it.
+pub fn solve_inspecting<S: Solution>(
+    solution: &S,
+    args: &Args,
+    input: Vec<String>,
+    inspect: impl FnOnce(&S::Input) -> Result<()>,
+) -> Result<()> {
+    if let Some(iterations) = args.bench.bench {
+        // Every benchmark iteration parses its own copy of the input, so this is just one more
+        inspect(&solution.parse(input.clone())?)?;
+
+        let benches = bench::bench(solution, &input, &args.parts(), iterations)?;
+        return bench::report(&args.bench, &benches);
+    }
+
+    print(
+        args,
+        crate::run_inspecting(solution, input, &args.parts(), inspect)?,
+    )
+}
+
+/// Prints the answers in `run` in the format selected by `args`.
+fn print(args: &Args, run: Run) -> Result<()> {
+    match args.output {
+        Format::Text => {
+            for part in run.parts {
+                println!("{}", part.answer);
+            }
+        }
+        Format::Json => output::print_json(&run)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anyhow::{Context, Result};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where to read a puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The conventional location of a day's input: `input/dayNN.txt`.
+    pub fn for_day(day: u8) -> Self {
+        InputSource::File(PathBuf::from("input").join(file_name(day)))
+    }
+
+    /// Opens the input for reading line by line.
+    pub fn reader(&self) -> Result<Box<dyn BufRead>> {
+        Ok(match self {
+            InputSource::Stdin => Box::new(std::io::stdin().lock()),
+            InputSource::File(path) => Box::new(BufReader::new(
+                File::open(path).with_context(|| format!("Failed to open input {path:?}"))?,
+            )),
+        })
+    }
+
+    /// Reads every line of the input.
+    pub fn read(&self) -> Result<Vec<String>> {
+        read_lines(self.reader()?).with_context(|| format!("Failed to read input {self}"))
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            _ => InputSource::File(PathBuf::from(s)),
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "-"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The file name of a day's input, e.g. `day01.txt`.
+pub fn file_name(day: u8) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// Reads every line from `reader`, without line endings.
+pub fn read_lines<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    Ok(reader.lines().collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Parses any type which can be built from the lines of a puzzle input directly from a reader.
+pub trait FromReader: Sized {
+    fn from_reader<R: BufRead>(reader: R) -> Result<Self>;
+}
+
+impl<T> FromReader for T
+where
+    T: TryFrom<Vec<String>, Error = anyhow::Error>,
+{
+    fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        T::try_from(read_lines(reader)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines(Vec<String>);
+
+    impl TryFrom<Vec<String>> for Lines {
+        type Error = anyhow::Error;
+
+        fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+            Ok(Self(value))
+        }
+    }
+
+    #[test]
+    fn from_reader() -> Result<()> {
+        let lines = Lines::from_reader("L68\r\nL30\nR48".as_bytes())?;
+        assert_eq!(vec!["L68", "L30", "R48"], lines.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_source() -> Result<()> {
+        assert_eq!(InputSource::Stdin, "-".parse()?);
+        assert_eq!(
+            InputSource::File(PathBuf::from("input/day03.txt")),
+            InputSource::for_day(3)
+        );
+
+        Ok(())
+    }
+}
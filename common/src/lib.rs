@@ -0,0 +1,264 @@
+//! Shared plumbing for running each day's [`Solution`].
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod warnings;
+
+use anyhow::{Result, bail};
+use log::debug;
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub use cli::main;
+pub use parse::ParseError;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Part::One => 1,
+                Part::Two => 2,
+            }
+        )
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => bail!("Invalid part: expected 1 or 2, got {s}"),
+        })
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u128)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A day's puzzle: parse the input once, then solve either part from it.
+pub trait Solution {
+    /// The day of the puzzle, from 1 to 12.
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    /// Parses the lines of the puzzle input.
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input>;
+
+    /// Solves the first part of the puzzle.
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Solves the second part of the puzzle.
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Solves the given `part` of the puzzle.
+    fn solve(&self, input: &Self::Input, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+fn nanos<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// The answer to a single part, along with how long it took to solve and any
+/// [warnings](warnings::warn) raised while solving it.
+#[derive(Clone, Debug, Serialize)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    #[serde(rename = "elapsed_ns", serialize_with = "nanos")]
+    pub elapsed: Duration,
+    pub warnings: Vec<String>,
+}
+
+/// The result of parsing a day's input and solving some of its parts. `warnings` holds those
+/// raised while parsing.
+#[derive(Clone, Debug, Serialize)]
+pub struct Run {
+    pub day: u8,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+    pub warnings: Vec<String>,
+}
+
+impl Run {
+    /// The answer to `part`, if it was solved.
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.parts
+            .iter()
+            .find(|p| p.part == part)
+            .map(|p| &p.answer)
+    }
+
+    /// Total time spent parsing and solving.
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+/// Parses `input` and solves each of `parts`, timing every stage.
+pub fn run<S: Solution>(solution: &S, input: Vec<String>, parts: &[Part]) -> Result<Run> {
+    run_inspecting(solution, input, parts, |_| Ok(()))
+}
+
+/// Like [`run`], but passes the parsed input to `inspect` before solving it. Time spent in
+/// `inspect` isn't counted towards any stage.
+pub fn run_inspecting<S: Solution>(
+    solution: &S,
+    input: Vec<String>,
+    parts: &[Part],
+    inspect: impl FnOnce(&S::Input) -> Result<()>,
+) -> Result<Run> {
+    // Discard anything left over from an earlier run on this thread
+    warnings::take();
+
+    let start = Instant::now();
+    let parsed = solution.parse(input)?;
+    let parse = start.elapsed();
+    debug!("Day {} parsed in {parse:?}", S::DAY);
+
+    let mut run = Run {
+        day: S::DAY,
+        parse,
+        parts: Vec::with_capacity(parts.len()),
+        warnings: warnings::take(),
+    };
+
+    inspect(&parsed)?;
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = solution.solve(&parsed, part)?;
+        let elapsed = start.elapsed();
+        debug!("Day {} part {part} solved in {elapsed:?}", S::DAY);
+
+        run.parts.push(PartRun {
+            part,
+            answer,
+            elapsed,
+            warnings: warnings::take(),
+        });
+    }
+
+    Ok(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_json() -> Result<()> {
+        let run = Run {
+            day: 10,
+            parse: Duration::from_nanos(5),
+            parts: vec![
+                PartRun {
+                    part: Part::One,
+                    answer: Answer::from(7usize),
+                    elapsed: Duration::from_nanos(10),
+                    warnings: vec!["Skipping manual 3".to_string()],
+                },
+                PartRun {
+                    part: Part::Two,
+                    answer: Answer::from("ABC"),
+                    elapsed: Duration::from_nanos(20),
+                    warnings: vec![],
+                },
+            ],
+            warnings: vec![],
+        };
+
+        assert_eq!(
+            r#"{"day":10,"parse_ns":5,"parts":[{"part":1,"answer":7,"elapsed_ns":10,"warnings":["Skipping manual 3"]},{"part":2,"answer":"ABC","elapsed_ns":20,"warnings":[]}],"warnings":[]}"#,
+            serde_json::to_string(&run)?
+        );
+
+        Ok(())
+    }
+}
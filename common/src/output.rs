@@ -0,0 +1,33 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// How answers are printed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// A JSON document including timings and warnings
+    Json,
+}
+
+/// Opens `path` for writing, treating `-` as stdout.
+pub fn create(path: &Path) -> Result<Box<dyn Write>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(std::io::stdout().lock()));
+    }
+
+    let file = File::create(path).with_context(|| format!("Failed to create {path:?}"))?;
+    Ok(Box::new(BufWriter::new(file)))
+}
+
+/// Prints `value` to stdout as pretty JSON.
+pub fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, value)?;
+    writeln!(stdout)?;
+    Ok(())
+}
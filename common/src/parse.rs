@@ -0,0 +1,143 @@
+//! A parse error shared by every day, pointing at the offending part of the input.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Puzzle input which couldn't be parsed. Displays as the position and what was expected there,
+/// followed by the input line with a caret under the offending column:
+///
+/// ```text
+/// line 3, column 1: expected `L` or `R`
+///   X68
+///   ^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number, if known.
+    pub line: Option<usize>,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// What should have been found at `column`.
+    pub expected: String,
+    /// The line of input containing the error.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error at the 1-based `column` of `source_line`.
+    pub fn new(source_line: &str, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column,
+            expected: expected.into(),
+            source_line: source_line.to_string(),
+        }
+    }
+
+    /// An error at the start of `token`, which must be a slice of `source_line`.
+    pub fn at(source_line: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(source_line, column_of(source_line, token), expected)
+    }
+
+    /// An error just past the end of `source_line`, for when it stops too early.
+    pub fn at_end(source_line: &str, expected: impl Into<String>) -> Self {
+        Self::new(source_line, source_line.chars().count() + 1, expected)
+    }
+
+    /// Sets the 1-based line number.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Moves an error raised while parsing `token` to the same position within `source_line`,
+    /// which `token` must be a slice of.
+    pub fn within(mut self, source_line: &str, token: &str) -> Self {
+        self.column += column_of(source_line, token) - 1;
+        self.source_line = source_line.to_string();
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}, ")?;
+        }
+        writeln!(f, "column {}: expected {}", self.column, self.expected)?;
+        writeln!(f, "  {}", self.source_line)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The 1-based column at which `token`, a slice of `source_line`, starts.
+fn column_of(source_line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(source_line.as_ptr() as usize)
+        .filter(|&offset| offset <= source_line.len())
+        .unwrap_or(0);
+
+    source_line[..offset].chars().count() + 1
+}
+
+/// Parses `token`, a slice of `source_line`, reporting `expected` at its position on failure.
+pub fn parse_token<T: FromStr>(
+    source_line: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source_line, token, expected))
+}
+
+/// Parses every line with `parse_line`, numbering any error with the line it came from.
+pub fn parse_lines<T>(
+    lines: &[String],
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = ParseError::new("X68", 1, "`L` or `R`").on_line(3);
+        assert_eq!(
+            "line 3, column 1: expected `L` or `R`\n  X68\n  ^",
+            error.to_string()
+        );
+
+        let line = "11-22,95x115";
+        let token = &line[6..];
+        let error = ParseError::at(token, &token[2..], "`-`").within(line, token);
+        assert_eq!(
+            "column 9: expected `-`\n  11-22,95x115\n          ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let lines = vec!["1".to_string(), "2".to_string(), "x".to_string()];
+
+        let error = parse_lines(&lines, |l| parse_token::<usize>(l, l, "a number")).unwrap_err();
+        assert_eq!(Some(3), error.line);
+        assert_eq!(1, error.column);
+        assert_eq!("a number", error.expected);
+
+        assert_eq!(
+            Ok(vec![1, 2]),
+            parse_lines(&lines[..2], |l| parse_token::<usize>(l, l, "a number"))
+        );
+    }
+}
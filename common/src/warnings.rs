@@ -0,0 +1,66 @@
+//! Non-fatal problems noticed while solving, such as input lines which had to be skipped.
+//!
+//! Warnings are logged as they happen and collected per thread, so that [`crate::run`] can attach
+//! them to the stage which raised them.
+
+use log::warn;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Logs `message` and records it for the stage currently running.
+pub fn warn(message: impl Into<String>) {
+    if QUIET.get() {
+        return;
+    }
+
+    let message = message.into();
+    warn!("{message}");
+    WARNINGS.with_borrow_mut(|warnings| warnings.push(message));
+}
+
+/// Removes and returns every warning recorded so far on this thread.
+pub fn take() -> Vec<String> {
+    WARNINGS.with_borrow_mut(std::mem::take)
+}
+
+/// Runs `f`, neither logging nor recording any warnings it raises on this thread.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let was_quiet = QUIET.replace(true);
+    let result = f();
+    QUIET.set(was_quiet);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_clears() {
+        take();
+        warn("first");
+        warn(String::from("second"));
+
+        assert_eq!(vec!["first", "second"], take());
+        assert!(take().is_empty());
+    }
+
+    #[test]
+    fn quietly_ignores() {
+        take();
+        assert_eq!(
+            3,
+            quietly(|| {
+                warn("ignored");
+                3
+            })
+        );
+        warn("recorded");
+
+        assert_eq!(vec!["recorded"], take());
+    }
+}
@@ -0,0 +1,601 @@
+//! Day 1: Secret Entrance.
+//!
+//! A safe's dial is turned by a list of [`Rotation`]s; the [`Password`] is how often it points at
+//! zero.
+
+pub mod lock;
+pub mod solver;
+
+use anyhow::{Result, bail};
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use log::{debug, trace};
+use serde::{Serialize, Serializer};
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+use std::convert::Infallible;
+use std::fmt::{Debug, Formatter};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+/// Which way the dial is turned.
+#[derive(Copy, Clone, Debug)]
+pub enum Direction {
+    /// Towards higher numbers.
+    R,
+    /// Towards lower numbers.
+    L,
+}
+
+/// A single turn of the dial, written as e.g. `L68` or `R14`.
+#[derive(Copy, Clone)]
+pub struct Rotation {
+    pub direction: Direction,
+    /// Number of clicks to turn.
+    pub value: usize,
+}
+
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let direction = match s.chars().next() {
+            Some('L') => Direction::L,
+            Some('R') => Direction::R,
+            _ => return Err(ParseError::new(s, 1, "`L` or `R`")),
+        };
+
+        let value = parse::parse_token(s, &s[1..], "a number of clicks")?;
+
+        Ok(Rotation { direction, value })
+    }
+}
+
+impl Debug for Rotation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}{}", self.direction, self.value)
+    }
+}
+
+impl Serialize for Rotation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{self:?}"))
+    }
+}
+
+/// The puzzle input: one [`Rotation`] per line.
+pub struct Rotations(pub Vec<Rotation>);
+
+impl TryFrom<Vec<String>> for Rotations {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        Ok(Self(parse::parse_lines(&value, Rotation::from_str)?))
+    }
+}
+
+/// How a [`Dial`] is numbered, where it starts, and which positions count towards the
+/// [`Password`]. The default is the puzzle's dial: `0` to `99`, starting at `50`, counting `0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DialConfig {
+    size: usize,
+    start: usize,
+    targets: BTreeSet<usize>,
+}
+
+impl DialConfig {
+    /// The puzzle dial's number of positions.
+    pub const DEFAULT_SIZE: usize = 100;
+    /// The position the puzzle dial starts at.
+    pub const DEFAULT_START: usize = 50;
+    /// The only position on the puzzle dial which counts towards the password.
+    pub const DEFAULT_TARGET: usize = 0;
+
+    /// A dial numbered `0` to `size - 1`, starting at `start`, counting each of `targets`.
+    pub fn new(
+        size: usize,
+        start: usize,
+        targets: impl IntoIterator<Item = usize>,
+    ) -> Result<Self> {
+        let targets: BTreeSet<usize> = targets.into_iter().collect();
+
+        if size == 0 {
+            bail!("Dial must have at least one position");
+        }
+        if start >= size {
+            bail!("Dial start {start} is not below its size {size}");
+        }
+        if targets.is_empty() {
+            bail!("Dial must have at least one target");
+        }
+        if let Some(&target) = targets.iter().find(|&&t| t >= size) {
+            bail!("Dial target {target} is not below its size {size}");
+        }
+
+        Ok(Self {
+            size,
+            start,
+            targets,
+        })
+    }
+
+    /// Number of positions on the dial.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The position the dial starts at.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The positions which count towards the password.
+    pub fn targets(&self) -> &BTreeSet<usize> {
+        &self.targets
+    }
+
+    /// Clicks turning `direction` from `position` until the dial first points at `target`, from
+    /// `1` up to a full turn.
+    fn first_hit(&self, position: usize, target: usize, direction: Direction) -> usize {
+        let distance = match direction {
+            Direction::R => (target + self.size - position) % self.size,
+            Direction::L => (position + self.size - target) % self.size,
+        };
+
+        if distance == 0 { self.size } else { distance }
+    }
+
+    /// Turns a dial at `position`, returning how many clicks left it pointing at a target and
+    /// where it ends up.
+    fn turn(&self, position: usize, rotation: Rotation) -> (usize, usize) {
+        let size = self.size;
+        let hits = self
+            .targets
+            .iter()
+            .map(|&target| {
+                // After the first time the dial reaches the target, it's hit every full turn
+                let first = self.first_hit(position, target, rotation.direction);
+                if rotation.value >= first {
+                    (rotation.value - first) / size + 1
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        let magnitude = rotation.value % size;
+        let end = match rotation.direction {
+            Direction::L => (position + size - magnitude) % size,
+            Direction::R => (position + magnitude) % size,
+        };
+
+        (hits, end)
+    }
+}
+
+impl Default for DialConfig {
+    fn default() -> Self {
+        Self {
+            size: Self::DEFAULT_SIZE,
+            start: Self::DEFAULT_START,
+            targets: BTreeSet::from([Self::DEFAULT_TARGET]),
+        }
+    }
+}
+
+/// A dial, numbered as described by its [`DialConfig`].
+#[derive(Debug)]
+pub struct Dial {
+    config: DialConfig,
+    position: usize,
+}
+
+impl Dial {
+    /// A dial pointing at the configured start position.
+    pub fn new(config: DialConfig) -> Self {
+        Self {
+            position: config.start,
+            config,
+        }
+    }
+
+    /// The number the dial currently points at.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Turns the dial, returning `1` if it ends up pointing at a target and `0` otherwise.
+    pub fn rotate(mut self, rotation: Rotation) -> (usize, Self) {
+        trace!("Rotating {self:?} {rotation:?}");
+
+        (_, self.position) = self.config.turn(self.position, rotation);
+
+        debug!("Rotated to {}", self.position);
+        let landed = usize::from(self.config.targets.contains(&self.position));
+        (landed, self)
+    }
+
+    /// Turns the dial, returning how many of its clicks left it pointing at a target. Takes
+    /// constant time per target however far the dial is turned.
+    pub fn rotate_by_clicks(mut self, rotation: Rotation) -> (usize, Self) {
+        trace!("Rotating {self:?} {rotation:?} counting clicks");
+
+        let hits;
+        (hits, self.position) = self.config.turn(self.position, rotation);
+
+        debug!(
+            "Passed a target {hits} time(s), rotated to {}",
+            self.position
+        );
+        (hits, self)
+    }
+
+    /// Turns the dial like [`Dial::rotate_by_clicks`], recording the turn as a [`Step`].
+    pub fn step(self, rotation: Rotation) -> (Step, Self) {
+        let start = self.position;
+        let (hits, dial) = self.rotate_by_clicks(rotation);
+
+        let step = Step {
+            rotation,
+            start,
+            end: dial.position,
+            hits,
+        };
+        (step, dial)
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new(DialConfig::default())
+    }
+}
+
+/// A single rotation of a dial, as recorded in a [`Trace`].
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct Step {
+    pub rotation: Rotation,
+    /// The position before the rotation.
+    pub start: usize,
+    /// The position after the rotation.
+    pub end: usize,
+    /// Clicks during the rotation which left the dial pointing at a target.
+    pub hits: usize,
+}
+
+/// Every rotation of a dial, in order, for plotting or diffing runs.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct Trace(pub Vec<Step>);
+
+impl Trace {
+    /// Turns a dial configured by `config` through every rotation, recording each one.
+    pub fn with_config(rotations: &Rotations, config: &DialConfig) -> Self {
+        let mut steps = Vec::with_capacity(rotations.0.len());
+
+        let mut dial = Dial::new(config.clone());
+        for &rotation in rotations.0.iter() {
+            let step;
+            (step, dial) = dial.step(rotation);
+            steps.push(step);
+        }
+
+        Self(steps)
+    }
+
+    /// Writes the trace as CSV, one row per rotation.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "rotation,start,end,hits")?;
+
+        for step in self.0.iter() {
+            writeln!(
+                writer,
+                "{:?},{},{},{}",
+                step.rotation, step.start, step.end, step.hits
+            )?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the trace as a JSON array, one object per rotation.
+    pub fn write_json<W: Write>(&self, mut writer: W) -> Result<()> {
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writeln!(writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// The two ways of counting how often the dial points at a target.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Password {
+    /// Rotations which leave the dial pointing at a target.
+    pub landings: usize,
+    /// Clicks which leave the dial pointing at a target, including during a rotation.
+    pub clicks: usize,
+}
+
+impl Password {
+    /// Turns a dial configured by `config` through every rotation, counting targets both ways.
+    pub fn with_config(rotations: &Rotations, config: &DialConfig) -> Self {
+        let Ok(password) = Self::fold(rotations.0.iter().map(Ok::<_, Infallible>), config);
+        password
+    }
+
+    /// Like [`Password::with_config`], but parses and applies each line as it's read, so memory
+    /// use doesn't grow with the number of rotations.
+    pub fn from_lines<L: AsRef<str>>(
+        lines: impl IntoIterator<Item = L>,
+        config: &DialConfig,
+    ) -> Result<Self> {
+        Self::parse_fold(lines.into_iter().map(Ok), config)
+    }
+
+    /// Like [`Password::from_lines`], reading lines from `reader`.
+    pub fn read<R: BufRead>(reader: R, config: &DialConfig) -> Result<Self> {
+        Self::parse_fold(
+            reader.lines().map(|l| l.map_err(anyhow::Error::from)),
+            config,
+        )
+    }
+
+    fn parse_fold<L: AsRef<str>>(
+        lines: impl Iterator<Item = Result<L>>,
+        config: &DialConfig,
+    ) -> Result<Self> {
+        let rotations = lines.enumerate().map(|(i, line)| {
+            Rotation::from_str(line?.as_ref()).map_err(|e| anyhow::Error::from(e.on_line(i + 1)))
+        });
+        Self::fold(rotations, config)
+    }
+
+    /// Turns the dial through each rotation in turn, stopping at the first error.
+    fn fold<R: Borrow<Rotation>, E>(
+        rotations: impl Iterator<Item = Result<R, E>>,
+        config: &DialConfig,
+    ) -> Result<Self, E> {
+        let mut password = Password::default();
+
+        let mut dial = Dial::new(config.clone());
+        for rotation in rotations {
+            let step;
+            (step, dial) = dial.step(*rotation?.borrow());
+            password.record(&step, config);
+        }
+
+        Ok(password)
+    }
+
+    fn record(&mut self, step: &Step, config: &DialConfig) {
+        self.clicks += step.hits;
+        if config.targets.contains(&step.end) {
+            self.landings += 1;
+        }
+    }
+}
+
+impl From<&Rotations> for Password {
+    fn from(value: &Rotations) -> Self {
+        Self::with_config(value, &DialConfig::default())
+    }
+}
+
+impl TryFrom<Vec<String>> for Password {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        Ok(Self::from(&Rotations::try_from(value)?))
+    }
+}
+
+/// [`Solution`] for day 1, using the dial described by `dial`.
+#[derive(Debug, Default)]
+pub struct Day01 {
+    pub dial: DialConfig,
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Rotations;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Rotations::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(Password::with_config(input, &self.dial).landings.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(Password::with_config(input, &self.dial).clicks.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_rotations() -> Result<()> {
+        let input = aoc_util::init_test()?;
+        let rotations = input
+            .into_iter()
+            .filter_map(|l| Rotation::from_str(l.as_str()).ok())
+            .collect::<Vec<Rotation>>();
+
+        assert_eq!(10, rotations.len());
+
+        let mut dial = Dial::default();
+        assert_eq!(50, dial.position());
+
+        let expected = vec![82, 52, 0, 95, 55, 0, 99, 0, 14, 32];
+
+        for (expected, rotation) in expected.into_iter().zip(rotations) {
+            (_, dial) = dial.rotate(rotation);
+            assert_eq!(expected, dial.position());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        assert_eq!(
+            Password {
+                landings: 3,
+                clicks: 6
+            },
+            Password::try_from(aoc_util::init_test()?)?
+        );
+
+        let day = Day01::default();
+        let rotations = day.parse(aoc_util::init_test()?)?;
+        assert_eq!(Answer::from(6usize), day.part2(&rotations)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = vec!["L68".to_string(), "X30".to_string()];
+        let Err(error) = Rotations::try_from(input) else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(
+            "line 2, column 1: expected `L` or `R`\n  X30\n  ^",
+            error.to_string()
+        );
+
+        let error = Rotation::from_str("R1x").unwrap_err();
+        assert_eq!(2, error.column);
+        assert_eq!("a number of clicks", error.expected);
+    }
+
+    #[test]
+    fn rotate_by_clicks() -> Result<()> {
+        let (zeros, dial) = Dial::default().rotate_by_clicks(Rotation::from_str("R1000")?);
+        assert_eq!(10, zeros);
+        assert_eq!(50, dial.position());
+
+        let (zeros, dial) = Dial::default().rotate_by_clicks(Rotation::from_str("L1000000000")?);
+        assert_eq!(10000000, zeros);
+        assert_eq!(50, dial.position());
+
+        // Compare against turning one click at a time
+        for config in [DialConfig::default(), DialConfig::new(7, 2, [0, 3])?] {
+            let size = config.size();
+            for start in [0, 1, size / 2, size - 1] {
+                for value in [
+                    0,
+                    1,
+                    size / 2,
+                    size - 1,
+                    size,
+                    size + 1,
+                    size * 2 + size / 2,
+                ] {
+                    for direction in [Direction::L, Direction::R] {
+                        let mut position = start;
+                        let mut expected = 0;
+                        for _ in 0..value {
+                            position = match direction {
+                                Direction::L => (position + size - 1) % size,
+                                Direction::R => (position + 1) % size,
+                            };
+                            if config.targets().contains(&position) {
+                                expected += 1;
+                            }
+                        }
+
+                        let rotation = Rotation { direction, value };
+                        let dial =
+                            Dial::new(DialConfig::new(size, start, config.targets().clone())?);
+                        let (hits, dial) = dial.rotate_by_clicks(rotation);
+                        assert_eq!(expected, hits, "{config:?}: {rotation:?} from {start}");
+                        assert_eq!(
+                            position,
+                            dial.position(),
+                            "{config:?}: {rotation:?} from {start}"
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn streaming() -> Result<()> {
+        let input = aoc_util::init_test()?;
+        let config = DialConfig::default();
+
+        let expected = Password::try_from(input.clone())?;
+        assert_eq!(expected, Password::from_lines(&input, &config)?);
+        assert_eq!(
+            expected,
+            Password::read(input.join("\n").as_bytes(), &config)?
+        );
+
+        // Lines are generated rather than collected up front
+        let lines = (0..1000).map(|i| if i % 2 == 0 { "R50" } else { "L50" });
+        let password = Password::from_lines(lines, &config)?;
+        assert_eq!(500, password.landings);
+        assert_eq!(500, password.clicks);
+
+        let error = Password::read("L68\nL30\nX5".as_bytes(), &config).unwrap_err();
+        assert_eq!(
+            Some(3),
+            error.downcast_ref::<ParseError>().and_then(|e| e.line)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn trace() -> Result<()> {
+        let rotations = Rotations::try_from(aoc_util::init_test()?)?;
+        let trace = Trace::with_config(&rotations, &DialConfig::default());
+
+        assert_eq!(10, trace.0.len());
+        assert_eq!(6, trace.0.iter().map(|s| s.hits).sum::<usize>());
+        assert!(trace.0.windows(2).all(|w| w[0].end == w[1].start));
+
+        let mut csv = Vec::new();
+        trace.write_csv(&mut csv)?;
+        let csv = String::from_utf8(csv)?;
+        assert_eq!(
+            vec!["rotation,start,end,hits", "L68,50,82,1", "L30,82,52,0"],
+            csv.lines().take(3).collect::<Vec<_>>()
+        );
+
+        let json = serde_json::to_string(&Trace(trace.0[..1].to_vec()))?;
+        assert_eq!(r#"[{"rotation":"L68","start":50,"end":82,"hits":1}]"#, json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dial_config() -> Result<()> {
+        assert!(DialConfig::new(0, 0, [0]).is_err());
+        assert!(DialConfig::new(10, 10, [0]).is_err());
+        assert!(DialConfig::new(10, 5, []).is_err());
+        assert!(DialConfig::new(10, 5, [3, 10]).is_err());
+
+        let rotations = Rotations::try_from(aoc_util::init_test()?)?;
+        assert_eq!(
+            Password::from(&rotations),
+            Password::with_config(&rotations, &DialConfig::new(100, 50, [0])?)
+        );
+
+        // Counting 0 and 50 on the same dial
+        let password = Password::with_config(&rotations, &DialConfig::new(100, 50, [0, 50])?);
+        assert_eq!(3, password.landings);
+        assert_eq!(10, password.clicks);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+//! Safes with several [`Dial`]s, each turned by a [`LockRotation`] such as `2:R15`.
+
+use crate::{Dial, DialConfig, Direction, Password, Rotation};
+use anyhow::{Result, bail};
+use common::parse::{self, ParseError};
+use log::debug;
+use std::str::FromStr;
+
+/// A [`Rotation`] of one dial of a [`CombinationLock`], written as e.g. `2:R15`. Dials are
+/// numbered from `1`.
+#[derive(Copy, Clone, Debug)]
+pub struct LockRotation {
+    pub dial: usize,
+    pub rotation: Rotation,
+}
+
+impl FromStr for LockRotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dial, rotation) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::at_end(s, "`:`"))?;
+
+        let dial = match parse::parse_token(s, dial, "a dial number")? {
+            0 => return Err(ParseError::at(s, dial, "a dial number from 1")),
+            dial => dial,
+        };
+        let rotation = Rotation::from_str(rotation).map_err(|e| e.within(s, rotation))?;
+
+        Ok(Self { dial, rotation })
+    }
+}
+
+/// The rotations of a [`CombinationLock`]: one [`LockRotation`] per line.
+pub struct LockRotations(pub Vec<LockRotation>);
+
+impl TryFrom<Vec<String>> for LockRotations {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        Ok(Self(parse::parse_lines(&value, LockRotation::from_str)?))
+    }
+}
+
+/// Several dials on one safe. With `carry`, a dial wrapping past zero turns the next dial one
+/// click in the same direction, like an odometer; the last dial's carries are lost.
+#[derive(Clone, Debug)]
+pub struct CombinationLock {
+    dials: Vec<DialConfig>,
+    carry: bool,
+}
+
+/// The [`Password`] of each dial of a [`CombinationLock`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LockPassword {
+    pub dials: Vec<Password>,
+}
+
+impl LockPassword {
+    /// The passwords of every dial added together.
+    pub fn total(&self) -> Password {
+        self.dials
+            .iter()
+            .fold(Password::default(), |total, p| Password {
+                landings: total.landings + p.landings,
+                clicks: total.clicks + p.clicks,
+            })
+    }
+}
+
+impl CombinationLock {
+    /// A lock with a dial for each of `dials`, which carry into each other if `carry` is set.
+    pub fn new(dials: Vec<DialConfig>, carry: bool) -> Result<Self> {
+        if dials.is_empty() {
+            bail!("Lock must have at least one dial");
+        }
+
+        Ok(Self { dials, carry })
+    }
+
+    /// Turns the lock's dials through every rotation, counting each dial's targets both ways. A
+    /// dial moved only by a carry still counts the targets it reaches.
+    pub fn password(&self, rotations: &LockRotations) -> Result<LockPassword> {
+        let mut dials: Vec<Option<Dial>> = self
+            .dials
+            .iter()
+            .map(|config| Some(Dial::new(config.clone())))
+            .collect();
+        let mut password = LockPassword {
+            dials: (0..dials.len()).map(|_| Password::default()).collect(),
+        };
+
+        for (i, lock_rotation) in rotations.0.iter().enumerate() {
+            if lock_rotation.dial > dials.len() {
+                bail!(
+                    "Rotation {} turns dial {}, but the lock has {} dial(s)",
+                    i + 1,
+                    lock_rotation.dial,
+                    dials.len()
+                );
+            }
+
+            let mut index = lock_rotation.dial - 1;
+            let mut rotation = lock_rotation.rotation;
+            loop {
+                let dial = dials[index].take().unwrap();
+                let wraps = wraps(&dial, rotation);
+
+                let (step, dial) = dial.step(rotation);
+                password.dials[index].record(&step, &dial.config);
+                dials[index] = Some(dial);
+
+                if !self.carry || wraps == 0 || index + 1 == dials.len() {
+                    break;
+                }
+
+                debug!("Dial {} carries {wraps} into the next", index + 1);
+                index += 1;
+                rotation = Rotation {
+                    direction: rotation.direction,
+                    value: wraps,
+                };
+            }
+        }
+
+        Ok(password)
+    }
+}
+
+/// How many times `rotation` takes `dial` between its highest position and zero.
+fn wraps(dial: &Dial, rotation: Rotation) -> usize {
+    let size = dial.config.size;
+    let position = dial.position;
+
+    match rotation.direction {
+        Direction::R => (position + rotation.value) / size,
+        Direction::L if rotation.value > position => (rotation.value - position - 1) / size + 1,
+        Direction::L => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotations(input: &str) -> Result<LockRotations> {
+        LockRotations::try_from(input.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn independent_dials() -> Result<()> {
+        let lock = CombinationLock::new(
+            vec![DialConfig::default(), DialConfig::new(10, 0, [0, 5])?],
+            false,
+        )?;
+
+        let password = lock.password(&rotations("1:L68\n2:R15\n1:L30\n1:R48\n2:L5")?)?;
+        assert_eq!(
+            vec![
+                Password {
+                    landings: 1,
+                    clicks: 2
+                },
+                Password {
+                    landings: 2,
+                    clicks: 4
+                },
+            ],
+            password.dials
+        );
+        assert_eq!(
+            Password {
+                landings: 3,
+                clicks: 6
+            },
+            password.total()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn carry() -> Result<()> {
+        let dial = DialConfig::new(10, 0, [0])?;
+        let lock = CombinationLock::new(vec![dial.clone(), dial.clone(), dial], true)?;
+
+        // 0 -> 5 wrapping twice, so the second dial turns to 2
+        let password = lock.password(&rotations("1:R25")?)?;
+        assert_eq!(2, password.dials[0].clicks);
+        assert_eq!(0, password.dials[1].clicks);
+
+        // The first dial wraps back past zero once, turning the second dial back to 1, then the
+        // second dial is turned back past zero to 9, wrapping the third dial to 9 too
+        let password = lock.password(&rotations("1:R25\n1:L6\n2:L2")?)?;
+        assert_eq!(
+            vec![
+                Password {
+                    landings: 0,
+                    clicks: 3
+                },
+                Password {
+                    landings: 0,
+                    clicks: 1
+                },
+                Password {
+                    landings: 0,
+                    clicks: 0
+                },
+            ],
+            password.dials
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let Err(error) = rotations("1:R5\n2:X5") else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(
+            "line 2, column 3: expected `L` or `R`\n  2:X5\n    ^",
+            error.to_string()
+        );
+
+        let error = LockRotation::from_str("0:R5").unwrap_err();
+        assert_eq!("a dial number from 1", error.expected);
+
+        let lock = CombinationLock::new(vec![DialConfig::default()], false).unwrap();
+        assert!(lock.password(&rotations("2:R5").unwrap()).is_err());
+    }
+}
@@ -1,150 +1,85 @@
 use anyhow::Result;
-use log::{debug, trace};
-use std::fmt::{Debug, Formatter};
-use std::str::FromStr;
-
-#[derive(Copy, Clone, Debug)]
-enum Direction {
-    R,
-    L,
+use clap::{Parser, ValueEnum};
+use common::Part;
+use common::output::{self, Format};
+use day01::{Day01, DialConfig, Password, Trace};
+use std::path::PathBuf;
+
+/// How a rotation trace is written.
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+enum TraceFormat {
+    #[default]
+    Csv,
+    Json,
 }
 
-#[derive(Copy, Clone)]
-struct Rotation {
-    direction: Direction,
-    value: usize,
-}
+#[derive(Debug, Parser)]
+#[command(about = "Solve an Advent of Code 2025 puzzle")]
+struct Cli {
+    #[command(flatten)]
+    args: common::cli::Args,
 
-impl FromStr for Rotation {
-    type Err = anyhow::Error;
+    /// Number of positions on the dial
+    #[arg(long, default_value_t = DialConfig::DEFAULT_SIZE)]
+    dial_size: usize,
 
-    fn from_str(s: &str) -> Result<Self> {
-        let direction = match s.chars().next() {
-            Some('L') => Direction::L,
-            Some('R') => Direction::R,
-            _ => anyhow::bail!("Invalid direction: expected 'L' or 'R'"),
-        };
+    /// Position the dial starts at
+    #[arg(long, default_value_t = DialConfig::DEFAULT_START)]
+    dial_start: usize,
 
-        let value = s[1..].parse::<usize>()?;
+    /// Comma-separated positions which count towards the password
+    #[arg(long, value_delimiter = ',', default_values_t = [DialConfig::DEFAULT_TARGET])]
+    dial_targets: Vec<usize>,
 
-        Ok(Rotation { direction, value })
-    }
-}
+    /// Also write every rotation's start, end and target hits, or `-` for stdout
+    #[arg(long, value_name = "PATH")]
+    trace: Option<PathBuf>,
 
-impl Debug for Rotation {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}{}", self.direction, self.value)
-    }
+    /// Format of the rotation trace
+    #[arg(long, value_enum, default_value_t, requires = "trace")]
+    trace_format: TraceFormat,
 }
 
-struct Rotations(Vec<Rotation>);
-
-impl TryFrom<Vec<String>> for Rotations {
-    type Error = anyhow::Error;
-
-    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
-        let count = value.len();
-
-        let rotations = value
-            .into_iter()
-            .filter_map(|l| Rotation::from_str(l.as_str()).ok())
-            .collect::<Vec<Rotation>>();
-
-        if rotations.len() == count {
-            Ok(Self(rotations))
-        } else {
-            Err(anyhow::anyhow!("Incorrect number of rotations"))
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Dial(usize);
-
-impl Dial {
-    const SIZE: usize = 100;
-
-    fn new() -> Self {
-        Self(50)
-    }
-
-    fn rotate(self, rotation: Rotation) -> (usize, Self) {
-        trace!("Rotating {self:?} {rotation:?}");
-
-        let magnitude = rotation.value % Self::SIZE;
-
-        let value = match rotation.direction {
-            Direction::L => (self.0 + Self::SIZE - magnitude) % Self::SIZE,
-            Direction::R => (self.0 + magnitude) % Self::SIZE,
-        };
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    common::cli::init_logger(cli.args.verbose);
 
-        let dial = Self(value);
+    let day = Day01 {
+        dial: DialConfig::new(cli.dial_size, cli.dial_start, cli.dial_targets)?,
+    };
 
-        debug!("Rotated to {dial:?}");
-        (if dial.0 == 0 { 1 } else { 0 }, dial)
+    // Timings need each part solved separately, so only stream when answers are all that's printed
+    if cli.trace.is_none() && cli.args.bench.bench.is_none() && cli.args.output == Format::Text {
+        return stream(&day, &cli.args);
     }
-}
 
-#[derive(Debug)]
-#[allow(dead_code)]
-struct Password(usize);
+    let input = common::cli::read_input::<Day01>(&cli.args)?;
 
-impl TryFrom<Vec<String>> for Password {
-    type Error = anyhow::Error;
-
-    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
-        let mut password = 0;
-
-        let mut dial = Dial::new();
-        for rotation in Rotations::try_from(value)?.0 {
-            let pw;
-            (pw, dial) = dial.rotate(rotation);
-
-            password += pw;
+    common::cli::solve_inspecting(&day, &cli.args, input, |rotations| {
+        if let Some(path) = &cli.trace {
+            let trace = Trace::with_config(rotations, &day.dial);
+            let writer = output::create(path)?;
+            match cli.trace_format {
+                TraceFormat::Csv => trace.write_csv(writer)?,
+                TraceFormat::Json => trace.write_json(writer)?,
+            }
         }
-
-        Ok(Self(password))
-    }
-}
-
-fn main() -> Result<()> {
-    let password = Password::try_from(aoc_util::init()?)?;
-    println!("{password:?}");
-
-    Ok(())
+        Ok(())
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Prints the selected parts' answers while reading the input, without holding every rotation in
+/// memory. Both parts come from the same pass, so there are no per-part timings to report.
+fn stream(day: &Day01, args: &common::cli::Args) -> Result<()> {
+    let reader = common::cli::input_source::<Day01>(args).reader()?;
+    let password = Password::read(reader, &day.dial)?;
 
-    #[test]
-    fn example_rotations() -> Result<()> {
-        let input = aoc_util::init_test()?;
-        let rotations = input
-            .into_iter()
-            .filter_map(|l| Rotation::from_str(l.as_str()).ok())
-            .collect::<Vec<Rotation>>();
-
-        assert_eq!(10, rotations.len());
-
-        let mut dial = Dial::new();
-        assert_eq!(50, dial.0);
-
-        let expected = vec![82, 52, 0, 95, 55, 0, 99, 0, 14, 32];
-
-        for (expected, rotation) in expected.into_iter().zip(rotations.into_iter()) {
-            (_, dial) = dial.rotate(rotation);
-            assert_eq!(expected, dial.0);
+    for part in args.parts() {
+        match part {
+            Part::One => println!("{}", password.landings),
+            Part::Two => println!("{}", password.clicks),
         }
-
-        Ok(())
     }
 
-    #[test]
-    fn example() -> Result<()> {
-        assert_eq!(3, Password::try_from(aoc_util::init_test()?)?.0);
-
-        Ok(())
-    }
+    Ok(())
 }
@@ -0,0 +1,158 @@
+//! Works backwards from what a dial should do to the [`Rotations`] which do it, for generating
+//! inputs with known answers.
+
+use crate::{DialConfig, Direction, Rotation, Rotations};
+use anyhow::{Result, anyhow, bail};
+use log::debug;
+
+/// What a solved sequence of rotations must achieve.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Goal {
+    /// Rotations must leave the dial at each of these positions, in order.
+    Positions(Vec<usize>),
+    /// Exactly this many clicks must leave the dial pointing at a target.
+    Hits(usize),
+}
+
+/// The shortest [`Rotations`] which take a dial configured by `config` from its start to
+/// `goal`: the fewest rotations, then the fewest clicks.
+pub fn solve(config: &DialConfig, goal: &Goal) -> Result<Rotations> {
+    let rotations = match goal {
+        Goal::Positions(positions) => to_positions(config, positions)?,
+        Goal::Hits(0) => Vec::new(),
+        // Every click adds at most one hit, so a single rotation can stop on any count
+        Goal::Hits(hits) => vec![to_hits(config, *hits)?],
+    };
+
+    debug!(
+        "Solved in {} rotation(s) and {} click(s)",
+        rotations.len(),
+        rotations.iter().map(|r| r.value).sum::<usize>()
+    );
+    Ok(Rotations(rotations))
+}
+
+/// One rotation per position, each turning whichever way is shorter.
+fn to_positions(config: &DialConfig, positions: &[usize]) -> Result<Vec<Rotation>> {
+    let size = config.size;
+
+    let mut rotations = Vec::with_capacity(positions.len());
+    let mut position = config.start;
+    for &next in positions {
+        if next >= size {
+            bail!("Position {next} is not below the dial's size {size}");
+        }
+
+        let right = (next + size - position) % size;
+        let left = (position + size - next) % size;
+        rotations.push(if right <= left {
+            Rotation {
+                direction: Direction::R,
+                value: right,
+            }
+        } else {
+            Rotation {
+                direction: Direction::L,
+                value: left,
+            }
+        });
+
+        position = next;
+    }
+
+    Ok(rotations)
+}
+
+/// The shorter of the rotations each way which stop on the click making the `hits`th hit.
+fn to_hits(config: &DialConfig, hits: usize) -> Result<Rotation> {
+    let targets = config.targets.len();
+    let (turns, nth) = ((hits - 1) / targets, (hits - 1) % targets);
+
+    [Direction::R, Direction::L]
+        .into_iter()
+        .filter_map(|direction| {
+            // Each full turn hits every target once more, so it's the `nth` target to be reached
+            // first, plus the full turns
+            let mut firsts: Vec<usize> = config
+                .targets
+                .iter()
+                .map(|&target| config.first_hit(config.start, target, direction))
+                .collect();
+            firsts.sort_unstable();
+
+            let value = turns.checked_mul(config.size)?.checked_add(firsts[nth])?;
+            Some(Rotation { direction, value })
+        })
+        .min_by_key(|rotation| rotation.value)
+        .ok_or_else(|| anyhow!("Too many clicks to hit a target {hits} times"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dial, Password};
+
+    #[test]
+    fn positions() -> Result<()> {
+        let config = DialConfig::default();
+
+        let rotations = solve(&config, &Goal::Positions(vec![82, 52, 0]))?;
+        assert_eq!("[R32, L30, R48]", format!("{:?}", rotations.0));
+
+        let mut dial = Dial::new(config.clone());
+        for (&rotation, expected) in rotations.0.iter().zip([82, 52, 0]) {
+            (_, dial) = dial.rotate(rotation);
+            assert_eq!(expected, dial.position());
+        }
+
+        assert!(solve(&config, &Goal::Positions(vec![100])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn hits() -> Result<()> {
+        let config = DialConfig::default();
+
+        assert!(solve(&config, &Goal::Hits(0))?.0.is_empty());
+
+        let rotations = solve(&config, &Goal::Hits(6))?;
+        assert_eq!(1, rotations.0.len());
+        assert_eq!(550, rotations.0[0].value);
+        assert_eq!(6, Password::with_config(&rotations, &config).clicks);
+
+        // Two targets a click apart can be hit twice with fewer clicks than a full turn
+        let config = DialConfig::new(10, 5, [1, 2])?;
+        let rotations = solve(&config, &Goal::Hits(2))?;
+        assert_eq!("[L4]", format!("{:?}", rotations.0));
+
+        // Compare against trying every rotation
+        let config = DialConfig::new(7, 2, [0, 3, 4])?;
+        for hits in 1..20 {
+            let shortest = [Direction::R, Direction::L]
+                .into_iter()
+                .flat_map(|direction| (0..100).map(move |value| Rotation { direction, value }))
+                .filter(|&rotation| Dial::new(config.clone()).rotate_by_clicks(rotation).0 == hits)
+                .map(|rotation| rotation.value)
+                .min();
+
+            let rotations = solve(&config, &Goal::Hits(hits))?;
+            assert_eq!(shortest, Some(rotations.0[0].value), "{hits} hits");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn many_hits() -> Result<()> {
+        let config = DialConfig::default();
+
+        let rotations = solve(&config, &Goal::Hits(1_000_000))?;
+        assert_eq!("[R99999950]", format!("{:?}", rotations.0));
+        assert_eq!(1_000_000, Password::with_config(&rotations, &config).clicks);
+
+        assert!(solve(&config, &Goal::Hits(usize::MAX)).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,865 @@
+//! Day 2: Gift Shop.
+//!
+//! Product IDs made of a digit pattern repeated several times are invalid; sum them over a list of
+//! [`Range`]s. IDs are decimal by default, but can be read and checked in any radix up to 36.
+
+pub mod report;
+
+use anyhow::{Result, anyhow, bail};
+use common::parse::ParseError;
+use common::{Answer, Solution, warnings};
+use log::debug;
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The base IDs are written and checked in, from 2 to 36.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Radix(u32);
+
+impl Radix {
+    /// Decimal, as the puzzle uses.
+    pub const DECIMAL: Radix = Radix(10);
+
+    /// Fails unless `radix` is from 2 to 36.
+    pub fn new(radix: u32) -> Result<Self> {
+        if !(2..=36).contains(&radix) {
+            bail!("Radix must be from 2 to 36, got {radix}");
+        }
+        Ok(Self(radix))
+    }
+
+    /// The radix as a number.
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for Radix {
+    fn default() -> Self {
+        Self::DECIMAL
+    }
+}
+
+impl FromStr for Radix {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s.parse()?)
+    }
+}
+
+impl Display for Radix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Which repeated patterns make an ID invalid. Every policy needs the pattern to repeat at least
+/// twice, so an ID is never invalid just for being its own pattern.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InvalidIdPolicy {
+    /// A pattern repeated exactly twice, e.g. `6464`.
+    ExactlyTwice,
+    /// A pattern repeated two or more times, e.g. `121212`.
+    AtLeastTwice,
+    /// A pattern repeated exactly this many times.
+    ExactlyK(usize),
+    /// A pattern of at least this many digits, repeated two or more times.
+    MinPatternLen(usize),
+}
+
+impl InvalidIdPolicy {
+    /// Whether a pattern of `pattern_len` digits repeated `repeats` times is invalid.
+    pub fn allows(&self, pattern_len: usize, repeats: usize) -> bool {
+        repeats >= 2
+            && match *self {
+                InvalidIdPolicy::ExactlyTwice => repeats == 2,
+                InvalidIdPolicy::AtLeastTwice => true,
+                InvalidIdPolicy::ExactlyK(k) => repeats == k,
+                InvalidIdPolicy::MinPatternLen(min) => pattern_len >= min,
+            }
+    }
+
+    /// Whether `id`, written in `radix`, is a pattern of digits repeated in a way this policy
+    /// considers invalid.
+    pub fn is_invalid(&self, id: u128, radix: Radix) -> bool {
+        self.explain(id, radix).is_some()
+    }
+
+    /// The shortest pattern, written in `radix`, and its number of repeats which make `id`
+    /// invalid under this policy, if any. `111111` is `1` six times, but under
+    /// [`InvalidIdPolicy::ExactlyTwice`] it's `111` twice.
+    pub fn explain(&self, id: u128, radix: Radix) -> Option<(String, usize)> {
+        let radix = radix.get();
+        let digits = to_digits(id, radix);
+        let len = digits.len();
+
+        (1..=len / 2)
+            .filter(|&pattern_len| len.is_multiple_of(pattern_len))
+            .filter(|&pattern_len| self.allows(pattern_len, len / pattern_len))
+            .find(|&pattern_len| {
+                digits
+                    .chunks(pattern_len)
+                    .all(|chunk| chunk == &digits[..pattern_len])
+            })
+            .map(|pattern_len| (to_string(&digits[..pattern_len], radix), len / pattern_len))
+    }
+}
+
+impl FromStr for InvalidIdPolicy {
+    type Err = anyhow::Error;
+
+    /// Parses `exactly-twice`, `at-least-twice`, `exactly:K` or `min-pattern-len:N`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = match s.split_once(':') {
+            Some((name, value)) => (name, Some(value.parse::<usize>()?)),
+            None => (s, None),
+        };
+
+        Ok(match (name, value) {
+            ("exactly-twice", None) => InvalidIdPolicy::ExactlyTwice,
+            ("at-least-twice", None) => InvalidIdPolicy::AtLeastTwice,
+            ("exactly", Some(k)) => InvalidIdPolicy::ExactlyK(k),
+            ("min-pattern-len", Some(n)) => InvalidIdPolicy::MinPatternLen(n),
+            _ => bail!(
+                "Unknown policy {s:?}, expected `exactly-twice`, `at-least-twice`, `exactly:K` or \
+                 `min-pattern-len:N`"
+            ),
+        })
+    }
+}
+
+/// The puzzle input: a single comma-separated line of [`Range`]s, whose IDs are written and
+/// checked in `radix`.
+#[derive(Debug)]
+pub struct Ranges {
+    ranges: Vec<Range>,
+    radix: Radix,
+    merged: Vec<Range>,
+    overlaps: Vec<Overlap>,
+}
+
+impl Ranges {
+    /// Ranges of IDs checked in `radix`. They're merged up front, so IDs in overlapping ranges are
+    /// only counted once.
+    pub fn new(ranges: Vec<Range>, radix: Radix) -> Self {
+        let (merged, overlaps) = normalize(&ranges);
+        Self {
+            ranges,
+            radix,
+            merged,
+            overlaps,
+        }
+    }
+
+    /// Parses ranges of IDs written in `radix`. Any ID can instead be given a `0x`, `0o` or `0b`
+    /// prefix, unless that letter is itself a digit in `radix`.
+    pub fn with_radix(value: Vec<String>, radix: Radix) -> Result<Self> {
+        if value.len() != 1 {
+            bail!("Expected a single line of ranges, got {}", value.len());
+        }
+
+        let mut ranges = Vec::new();
+        for l in value {
+            for r in l.split(',') {
+                ranges.push(Range::parse(r, radix).map_err(|e| e.within(&l, r).on_line(1))?);
+            }
+        }
+        Ok(Self::new(ranges, radix))
+    }
+
+    /// The ranges as given, in input order.
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// The radix IDs are checked in.
+    pub fn radix(&self) -> Radix {
+        self.radix
+    }
+
+    /// The ranges merged into the fewest disjoint ranges, in order. Ranges which are merely
+    /// adjacent are merged too.
+    pub fn merged(&self) -> &[Range] {
+        &self.merged
+    }
+
+    /// Every pair of ranges which share IDs, ordered by their positions in the input.
+    pub fn overlaps(&self) -> &[Overlap] {
+        &self.overlaps
+    }
+
+    /// Sums every ID which is invalid under `policy`, counting IDs in overlapping ranges once.
+    /// Fails rather than wrapping if the sum doesn't fit in a `u128`.
+    pub fn invalid_id_sum(&self, policy: InvalidIdPolicy) -> Result<u128> {
+        let mut sum: u128 = 0;
+        for id in self
+            .merged
+            .iter()
+            .flat_map(|r| r.invalid_ids(policy, self.radix))
+        {
+            sum = sum.checked_add(id).ok_or_else(sum_overflow)?;
+        }
+
+        Ok(sum)
+    }
+
+    /// Like [`Ranges::invalid_id_sum`], but shares the ranges out between up to `threads`
+    /// threads, each taking the next unclaimed range as it finishes the last. With only one thread
+    /// to use, sums on the calling thread instead.
+    pub fn par_invalid_id_sum(&self, policy: InvalidIdPolicy, threads: usize) -> Result<u128> {
+        let ranges = &self.merged;
+        let threads = threads.clamp(1, ranges.len().max(1));
+        if threads == 1 {
+            return self.invalid_id_sum(policy);
+        }
+        debug!("Summing {} range(s) on {threads} thread(s)", ranges.len());
+
+        let next = AtomicUsize::new(0);
+        let sums: Vec<Option<u128>> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut sum: u128 = 0;
+                        while let Some(range) = ranges.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            for id in range.invalid_ids(policy, self.radix) {
+                                sum = sum.checked_add(id)?;
+                            }
+                        }
+                        Some(sum)
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("Worker thread panicked"))
+                .collect()
+        });
+
+        // Every ID is positive, so the total overflows exactly when the serial sum would
+        sums.into_iter()
+            .try_fold(0u128, |total, sum| total.checked_add(sum?))
+            .ok_or_else(sum_overflow)
+    }
+}
+
+impl TryFrom<Vec<String>> for Ranges {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> std::result::Result<Self, Self::Error> {
+        Self::with_radix(value, Radix::DECIMAL)
+    }
+}
+
+/// `ranges` merged into the fewest disjoint ranges, in order, along with every pair of them which
+/// share IDs. Ranges which are merely adjacent are merged but don't overlap.
+fn normalize(ranges: &[Range]) -> (Vec<Range>, Vec<Overlap>) {
+    // Parsing rejects reversed ranges, but one built directly holds no IDs, so can't overlap
+    let mut order: Vec<usize> = (0..ranges.len())
+        .filter(|&i| ranges[i].0 <= ranges[i].1)
+        .collect();
+    order.sort_by_key(|&i| (ranges[i].0, ranges[i].1));
+
+    let mut merged: Vec<Range> = Vec::new();
+    let mut overlaps = Vec::new();
+
+    // Earlier ranges which reach at least as far as the current range's start
+    let mut active: Vec<usize> = Vec::new();
+
+    for i in order {
+        let range = ranges[i];
+
+        active.retain(|&j| ranges[j].1 >= range.0);
+        overlaps.extend(active.iter().map(|&j| Overlap {
+            first: j.min(i),
+            second: j.max(i),
+        }));
+        active.push(i);
+
+        match merged.last_mut() {
+            Some(last) if range.0 <= last.1.saturating_add(1) => last.1 = last.1.max(range.1),
+            _ => merged.push(range),
+        }
+    }
+
+    overlaps.sort_unstable_by_key(|o| (o.first, o.second));
+    debug!("Merged into {merged:?}, overlaps: {overlaps:?}");
+    (merged, overlaps)
+}
+
+fn sum_overflow() -> anyhow::Error {
+    anyhow!("Sum of invalid IDs doesn't fit in a u128")
+}
+
+/// Two of the input [`Ranges`] which share IDs, by their 0-based positions in the input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub first: usize,
+    pub second: usize,
+}
+
+impl Display for Overlap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Ranges {} and {} overlap",
+            self.first + 1,
+            self.second + 1
+        )
+    }
+}
+
+/// An inclusive range of IDs, written as `start-end`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Range(pub u128, pub u128);
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Radix::DECIMAL)
+    }
+}
+
+impl Range {
+    /// Parses `start-end` with IDs written in `radix`, or with a prefix as described by
+    /// [`Ranges::with_radix`]. The end can't come before the start.
+    pub fn parse(s: &str, radix: Radix) -> Result<Self, ParseError> {
+        let radix = radix.get();
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::at_end(s, "`-`"))?;
+        let range = Self(
+            parse_id(s, start, radix, "a start ID")?,
+            parse_id(s, end, radix, "an end ID")?,
+        );
+
+        if range.1 < range.0 {
+            return Err(ParseError::at(
+                s,
+                end,
+                format!("an end ID no smaller than {start}"),
+            ));
+        }
+
+        Ok(range)
+    }
+
+    /// Every ID in the range, in order, which is invalid under `policy` when written in `radix`.
+    /// IDs are built from their patterns rather than found by checking every ID, so this takes
+    /// time proportional to the number found rather than the range's width.
+    pub fn invalid_ids(&self, policy: InvalidIdPolicy, radix: Radix) -> Vec<u128> {
+        let radix = radix.get();
+        let (start, end) = (self.0, self.1);
+        let base = radix as u128;
+
+        // An ID like `1111` is both `1` and `11` repeated, so collect into a set
+        let mut invalid_ids = BTreeSet::new();
+
+        for len in digit_count(start, radix)..=digit_count(end, radix) {
+            // The largest IDs have as many digits as fit, so `radix^len` itself may not
+            let largest = base.checked_pow(len).map_or(u128::MAX, |p| p - 1);
+            let (min, max) = (start.max(base.pow(len - 1)), end.min(largest));
+
+            for pattern_len in (1..=len / 2).filter(|&p| len.is_multiple_of(p)) {
+                if !policy.allows(pattern_len as usize, (len / pattern_len) as usize) {
+                    continue;
+                }
+
+                // Repeating a pattern multiplies it by 1, radix^pattern_len, radix^(2 * pattern_len)...
+                let Some(multiplier) = repeater(base, pattern_len, len / pattern_len) else {
+                    continue;
+                };
+                let first = min.div_ceil(multiplier).max(base.pow(pattern_len - 1));
+                let last = (max / multiplier).min(base.pow(pattern_len) - 1);
+
+                invalid_ids.extend((first..=last).map(|pattern| pattern * multiplier));
+            }
+        }
+
+        let invalid_ids: Vec<u128> = invalid_ids.into_iter().collect();
+        debug!("{:?}", invalid_ids);
+        invalid_ids
+    }
+}
+
+/// Parses `token`, a slice of `source_line`, as an ID in `radix` or with a radix prefix.
+fn parse_id(
+    source_line: &str,
+    token: &str,
+    radix: u32,
+    expected: &str,
+) -> Result<u128, ParseError> {
+    let mut chars = token.chars();
+    let prefixed = match (chars.next(), chars.next()) {
+        (Some('0'), Some(c)) if !c.is_digit(radix) => match c.to_ascii_lowercase() {
+            'x' => Some(16),
+            'o' => Some(8),
+            'b' => Some(2),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let (digits, radix) = match prefixed {
+        Some(radix) => (&token[2..], radix),
+        None => (token, radix),
+    };
+
+    // `from_str_radix` would accept a leading `+`
+    if digits.starts_with('+') {
+        return Err(ParseError::at(source_line, digits, expected));
+    }
+
+    u128::from_str_radix(digits, radix).map_err(|_| ParseError::at(source_line, digits, expected))
+}
+
+/// The digits of `n` written in `radix`, most significant first.
+fn to_digits(mut n: u128, radix: u32) -> Vec<u32> {
+    let radix = radix as u128;
+
+    let mut digits = Vec::new();
+    loop {
+        digits.push((n % radix) as u32);
+        n /= radix;
+        if n == 0 {
+            break;
+        }
+    }
+
+    digits.reverse();
+    digits
+}
+
+/// `digits` written out in `radix`, using lowercase letters for digits above 9.
+fn to_string(digits: &[u32], radix: u32) -> String {
+    digits
+        .iter()
+        .map(|&d| char::from_digit(d, radix).unwrap())
+        .collect()
+}
+
+/// `id` written out in `radix`.
+fn format_id(id: u128, radix: u32) -> String {
+    to_string(&to_digits(id, radix), radix)
+}
+
+/// What a pattern of `pattern_len` digits is multiplied by to repeat it `repeats` times, or `None`
+/// if that doesn't fit in a `u128`.
+fn repeater(base: u128, pattern_len: u32, repeats: u32) -> Option<u128> {
+    let shift = base.pow(pattern_len);
+    (1..repeats).try_fold(1u128, |multiplier, _| {
+        multiplier.checked_mul(shift)?.checked_add(1)
+    })
+}
+
+/// The number of digits in `n` written in `radix`.
+fn digit_count(n: u128, radix: u32) -> u32 {
+    n.checked_ilog(radix as u128).unwrap_or(0) + 1
+}
+
+/// [`Solution`] for day 2, reading and checking IDs in `radix` and summing ranges on up to
+/// `threads` threads. Overlapping ranges are counted once with a warning, or rejected if `strict`.
+#[derive(Debug)]
+pub struct Day02 {
+    pub radix: Radix,
+    pub strict: bool,
+    pub threads: usize,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Self {
+            radix: Radix::DECIMAL,
+            strict: false,
+            threads: 1,
+        }
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Ranges;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        let ranges = Ranges::with_radix(input, self.radix)?;
+
+        for overlap in ranges.overlaps() {
+            if self.strict {
+                bail!("{overlap}");
+            }
+            warnings::warn(format!("{overlap}, counting their shared IDs once"));
+        }
+
+        Ok(ranges)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .par_invalid_id_sum(InvalidIdPolicy::ExactlyTwice, self.threads)?
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .par_invalid_id_sum(InvalidIdPolicy::AtLeastTwice, self.threads)?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_doubled() -> Result<()> {
+        let policy = InvalidIdPolicy::ExactlyTwice;
+        assert!(policy.is_invalid(55, Radix::DECIMAL));
+        assert!(policy.is_invalid(6464, Radix::DECIMAL));
+        assert!(policy.is_invalid(123123, Radix::DECIMAL));
+        assert!(!policy.is_invalid(101, Radix::DECIMAL));
+        assert!(!policy.is_invalid(111, Radix::DECIMAL));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_invalid() -> Result<()> {
+        let policy = InvalidIdPolicy::AtLeastTwice;
+        assert!(policy.is_invalid(12341234, Radix::DECIMAL));
+        assert!(policy.is_invalid(123123123, Radix::DECIMAL));
+        assert!(policy.is_invalid(1212121212, Radix::DECIMAL));
+        assert!(policy.is_invalid(1111111, Radix::DECIMAL));
+
+        Ok(())
+    }
+
+    #[test]
+    fn policies() {
+        assert!(InvalidIdPolicy::ExactlyK(3).is_invalid(121212, Radix::DECIMAL));
+        assert!(!InvalidIdPolicy::ExactlyK(3).is_invalid(1212, Radix::DECIMAL));
+        // `111111` is `11` three times, even though it's also `1` six times
+        assert!(InvalidIdPolicy::ExactlyK(3).is_invalid(111111, Radix::DECIMAL));
+        assert!(!InvalidIdPolicy::ExactlyK(1).is_invalid(1234, Radix::DECIMAL));
+
+        assert!(InvalidIdPolicy::MinPatternLen(2).is_invalid(1111, Radix::DECIMAL));
+        assert!(!InvalidIdPolicy::MinPatternLen(2).is_invalid(111, Radix::DECIMAL));
+        assert!(!InvalidIdPolicy::MinPatternLen(3).is_invalid(121212, Radix::DECIMAL));
+
+        let range = Range(100000, 999999);
+        for policy in [
+            InvalidIdPolicy::ExactlyK(3),
+            InvalidIdPolicy::MinPatternLen(2),
+        ] {
+            let invalid_ids = range.invalid_ids(policy, Radix::DECIMAL);
+            assert!(
+                invalid_ids
+                    .iter()
+                    .all(|&i| policy.is_invalid(i, Radix::DECIMAL))
+            );
+            assert_eq!(
+                (range.0..=range.1)
+                    .filter(|&i| policy.is_invalid(i, Radix::DECIMAL))
+                    .count(),
+                invalid_ids.len()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let Err(error) = Ranges::try_from(vec!["11-22,95115".to_string()]) else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(
+            "line 1, column 12: expected `-`\n  11-22,95115\n             ^",
+            error.to_string()
+        );
+
+        let error = Range::from_str("95-x").unwrap_err();
+        assert_eq!(4, error.column);
+        assert_eq!("an end ID", error.expected);
+
+        // Rejected even if the ranges would otherwise be accepted
+        let Err(error) = Ranges::try_from(vec!["11-22,115-95".to_string()]) else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(
+            "line 1, column 11: expected an end ID no smaller than 115\n  11-22,115-95\n            ^",
+            error.to_string()
+        );
+        assert!(Range::from_str("95-95").is_ok());
+
+        // No signs, even after a prefix
+        let error = Range::from_str("+12-+34").unwrap_err();
+        assert_eq!(1, error.column);
+        assert_eq!("a start ID", error.expected);
+        let error = Range::from_str("12-+34").unwrap_err();
+        assert_eq!(4, error.column);
+        let error = Range::from_str("0x+1a-0x2b").unwrap_err();
+        assert_eq!(3, error.column);
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        let expected = vec![2, 2, 2, 1, 1, 0, 1, 1, 1, 1, 1];
+        let ranges = Ranges::try_from(aoc_util::init_test()?)?;
+
+        for (expected, range) in expected.into_iter().zip(ranges.ranges()) {
+            assert_eq!(
+                expected,
+                range
+                    .invalid_ids(InvalidIdPolicy::AtLeastTwice, Radix::DECIMAL)
+                    .len()
+            );
+        }
+
+        assert_eq!(
+            1227775554,
+            ranges.invalid_id_sum(InvalidIdPolicy::ExactlyTwice)?
+        );
+        assert_eq!(
+            4174379265,
+            ranges.invalid_id_sum(InvalidIdPolicy::AtLeastTwice)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn enumeration() {
+        // Matches checking every ID, including across digit lengths and duplicate patterns
+        for range in [Range(0, 1200), Range(95, 115), Range(99990, 1010102)] {
+            for policy in [InvalidIdPolicy::ExactlyTwice, InvalidIdPolicy::AtLeastTwice] {
+                let scanned: Vec<u128> = (range.0..=range.1)
+                    .filter(|&i| policy.is_invalid(i, Radix::DECIMAL))
+                    .collect();
+                assert_eq!(scanned, range.invalid_ids(policy, Radix::DECIMAL));
+            }
+        }
+
+        // Far too wide to check every ID
+        let start = 10u128.pow(17);
+        let invalid_ids = Range(start, start + 10u128.pow(12))
+            .invalid_ids(InvalidIdPolicy::AtLeastTwice, Radix::DECIMAL);
+        assert_eq!(Some(&100000000100000000), invalid_ids.first());
+        assert!(
+            invalid_ids
+                .iter()
+                .all(|&i| InvalidIdPolicy::AtLeastTwice.is_invalid(i, Radix::DECIMAL))
+        );
+
+        // IDs up to the largest `u128` don't overflow
+        assert!(
+            Range(u128::MAX - 10, u128::MAX)
+                .invalid_ids(InvalidIdPolicy::AtLeastTwice, Radix::DECIMAL)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn radix() -> Result<()> {
+        // `0x1a1a` and `0x2f2f` in hexadecimal, but not in decimal
+        let policy = InvalidIdPolicy::ExactlyTwice;
+        let hex = Radix::new(16)?;
+        assert!(policy.is_invalid(0x1a1a, hex));
+        assert!(!policy.is_invalid(0x1a1a, Radix::DECIMAL));
+        assert!(policy.is_invalid(0b1010, Radix::new(2)?));
+
+        let ranges = Ranges::with_radix(vec!["0x1a1a-0x1a20,2f00-2fff".to_string()], hex)?;
+        assert_eq!(
+            vec![Range(0x1a1a, 0x1a20), Range(0x2f00, 0x2fff)],
+            ranges.ranges()
+        );
+        assert_eq!(0x1a1a + 0x2f2f, ranges.invalid_id_sum(policy)?);
+
+        // Base 36 uses every letter, so `0x` is just a number
+        let ranges = Ranges::with_radix(vec!["0x-zz".to_string()], Radix::new(36)?)?;
+        assert_eq!(vec![Range(33, 36 * 36 - 1)], ranges.ranges());
+        assert_eq!(
+            (1..36).map(|d| d * 37).sum::<u128>(),
+            ranges.invalid_id_sum(policy)?
+        );
+
+        // Enumerating matches checking every ID
+        let range = Range(0, 5000);
+        for radix in [2, 3, 16, 36] {
+            let radix = Radix::new(radix)?;
+            let scanned: Vec<u128> = (range.0..=range.1)
+                .filter(|&i| InvalidIdPolicy::AtLeastTwice.is_invalid(i, radix))
+                .collect();
+            assert_eq!(
+                scanned,
+                range.invalid_ids(InvalidIdPolicy::AtLeastTwice, radix)
+            );
+        }
+
+        assert!(Radix::new(37).is_err());
+        assert!(Radix::new(1).is_err());
+        assert!(Radix::new(0).is_err());
+        assert!("37".parse::<Radix>().is_err());
+        let error = Range::parse("1a-0xg", hex).unwrap_err();
+        assert_eq!(6, error.column);
+
+        // `0x1b` is 27, so comes before `1c`
+        let error = Range::parse("1c-0x1b", hex).unwrap_err();
+        assert_eq!(4, error.column);
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_ids() -> Result<()> {
+        // Too large for a `u64`
+        let ranges = Ranges::try_from(vec![
+            "98765432109876543210-98765432109876543219".to_string(),
+        ])?;
+        assert_eq!(
+            98765432109876543210,
+            ranges.invalid_id_sum(InvalidIdPolicy::ExactlyTwice)?
+        );
+
+        // Ten doubled 38 digit IDs add up to more than fits
+        let multiplier = 10u128.pow(19) + 1;
+        let pattern = 10u128.pow(19) - 10;
+        let ranges = Ranges::new(
+            vec![Range(pattern * multiplier, (pattern + 9) * multiplier)],
+            Radix::DECIMAL,
+        );
+        assert_eq!(
+            10,
+            ranges.ranges()[0]
+                .invalid_ids(InvalidIdPolicy::ExactlyTwice, Radix::DECIMAL)
+                .len()
+        );
+        assert!(
+            ranges
+                .invalid_id_sum(InvalidIdPolicy::ExactlyTwice)
+                .is_err()
+        );
+        assert!(
+            ranges
+                .par_invalid_id_sum(InvalidIdPolicy::ExactlyTwice, 4)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlaps() -> Result<()> {
+        let ranges = Ranges::try_from(vec!["50-60,11-22,15-30,30-40,55-55,41-45".to_string()])?;
+
+        assert_eq!(&[Range(11, 45), Range(50, 60)], ranges.merged());
+        assert_eq!(
+            vec![
+                Overlap {
+                    first: 0,
+                    second: 4
+                },
+                Overlap {
+                    first: 1,
+                    second: 2
+                },
+                Overlap {
+                    first: 2,
+                    second: 3
+                },
+            ],
+            ranges.overlaps()
+        );
+
+        // Every pair sharing IDs is reported, not just one per range
+        let ranges = Ranges::try_from(vec!["1-100,5-10,8-20".to_string()])?;
+        assert_eq!(
+            vec![(0, 1), (0, 2), (1, 2)],
+            ranges
+                .overlaps()
+                .iter()
+                .map(|o| (o.first, o.second))
+                .collect::<Vec<_>>()
+        );
+
+        // Duplicates are only counted once
+        let ranges = Ranges::try_from(vec!["11-22,11-22,20-25".to_string()])?;
+        assert_eq!(33, ranges.invalid_id_sum(InvalidIdPolicy::AtLeastTwice)?);
+
+        warnings::take();
+        let input = vec!["11-22,11-22".to_string()];
+        Day02::default().parse(input.clone())?;
+        assert_eq!(
+            vec!["Ranges 1 and 2 overlap, counting their shared IDs once"],
+            warnings::take()
+        );
+
+        let strict = Day02 {
+            strict: true,
+            ..Day02::default()
+        };
+        assert_eq!(
+            "Ranges 1 and 2 overlap",
+            strict.parse(input).unwrap_err().to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parallel() -> Result<()> {
+        let ranges = Ranges::try_from(aoc_util::init_test()?)?;
+        for policy in [InvalidIdPolicy::ExactlyTwice, InvalidIdPolicy::AtLeastTwice] {
+            let serial = ranges.invalid_id_sum(policy)?;
+            for threads in [0, 1, 3, 64] {
+                assert_eq!(serial, ranges.par_invalid_id_sum(policy, threads)?);
+            }
+        }
+
+        let empty = Ranges::new(Vec::new(), Radix::DECIMAL);
+        assert_eq!(
+            0,
+            empty.par_invalid_id_sum(InvalidIdPolicy::AtLeastTwice, 4)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn explain() -> Result<()> {
+        let explain = |policy: InvalidIdPolicy, id| policy.explain(id, Radix::DECIMAL);
+
+        assert_eq!(
+            Some(("12".to_string(), 3)),
+            explain(InvalidIdPolicy::AtLeastTwice, 121212)
+        );
+        assert_eq!(
+            Some(("1".to_string(), 6)),
+            explain(InvalidIdPolicy::AtLeastTwice, 111111)
+        );
+        assert_eq!(
+            Some(("111".to_string(), 2)),
+            explain(InvalidIdPolicy::ExactlyTwice, 111111)
+        );
+        assert_eq!(None, explain(InvalidIdPolicy::ExactlyTwice, 121212));
+        assert_eq!(
+            Some(("1a".to_string(), 2)),
+            InvalidIdPolicy::ExactlyTwice.explain(0x1a1a, Radix::new(16)?)
+        );
+
+        assert_eq!(
+            InvalidIdPolicy::ExactlyK(3),
+            "exactly:3".parse::<InvalidIdPolicy>()?
+        );
+        assert_eq!(
+            InvalidIdPolicy::MinPatternLen(2),
+            "min-pattern-len:2".parse::<InvalidIdPolicy>()?
+        );
+        assert!("exactly".parse::<InvalidIdPolicy>().is_err());
+
+        Ok(())
+    }
+}
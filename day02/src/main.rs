@@ -1,116 +1,66 @@
-use anyhow::{bail, Result};
-use log::debug;
-use std::fmt::Debug;
-use std::str::FromStr;
-
-#[derive(Debug)]
-struct Ranges(Vec<Range>);
-
-impl Ranges {
-    fn invalid_id_sum(&self) -> usize {
-        self.0.iter().flat_map(Range::invalid_ids).sum()
-    }
-}
-
-impl TryFrom<Vec<String>> for Ranges {
-    type Error = anyhow::Error;
-
-    fn try_from(value: Vec<String>) -> std::result::Result<Self, Self::Error> {
-        if value.len() != 1 {
-            bail!("Expected a single range, got {}", value.len());
-        }
-
-        let mut ranges = Vec::new();
-        for l in value {
-            for r in l.split(',') {
-                ranges.push(Range::from_str(r)?);
-            }
-        }
-        Ok(Self(ranges))
-    }
-}
-
-#[derive(Debug)]
-struct Range(usize, usize);
-
-impl FromStr for Range {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once('-').unwrap();
-        Ok(Self(start.parse::<usize>()?, end.parse::<usize>()?))
-    }
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use common::{Solution, output};
+use day02::{Day02, InvalidIdPolicy, Radix, report};
+use std::num::NonZeroUsize;
+use std::thread;
+
+/// How the invalid ID report is printed.
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum ReportFormat {
+    Table,
+    Json,
 }
 
-impl Range {
-    fn invalid_ids(&self) -> Vec<usize> {
-        let mut invalid_ids = Vec::new();
+#[derive(Debug, Parser)]
+#[command(about = "Solve an Advent of Code 2025 puzzle")]
+struct Cli {
+    #[command(flatten)]
+    args: common::cli::Args,
 
-        for i in self.0..=self.1 {
-            if Self::is_invalid_id(i) {
-                invalid_ids.push(i);
-            }
-        }
+    /// Radix IDs are written and checked in, from 2 to 36
+    #[arg(long, default_value_t)]
+    radix: Radix,
 
-        debug!("{:?}", invalid_ids);
-        invalid_ids
-    }
-
-    fn is_invalid_id(id: usize) -> bool {
-        let s = id.to_string();
-        let len = s.len();
+    /// Reject overlapping ranges instead of counting their shared IDs once
+    #[arg(long)]
+    strict: bool,
 
-        // Try all possible pattern lengths from 1 to len/2
-        for pattern_len in 1..=len / 2 {
-            if len.is_multiple_of(pattern_len) {
-                let pattern = &s[0..pattern_len];
-                let repeats = len / pattern_len;
+    /// Number of threads to sum ranges on [default: available parallelism]
+    #[arg(long)]
+    threads: Option<NonZeroUsize>,
 
-                // Pattern must repeat at least twice
-                if repeats >= 2 && pattern.repeat(repeats) == s {
-                    return true;
-                }
-            }
-        }
+    /// List each range's invalid IDs and why they're invalid, instead of printing answers
+    #[arg(long, value_name = "FORMAT")]
+    report: Option<ReportFormat>,
 
-        false
-    }
+    /// Which IDs the report treats as invalid: `exactly-twice`, `at-least-twice`, `exactly:K` or
+    /// `min-pattern-len:N`
+    #[arg(long, default_value = "at-least-twice", requires = "report")]
+    policy: InvalidIdPolicy,
 }
 
 fn main() -> Result<()> {
-    let ranges = Ranges::try_from(aoc_util::init()?)?;
-    let sum = ranges.invalid_id_sum();
-
-    println!("{sum}");
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn is_invalid() -> Result<()> {
-        assert!(Range::is_invalid_id(12341234));
-        assert!(Range::is_invalid_id(123123123));
-        assert!(Range::is_invalid_id(1212121212));
-        assert!(Range::is_invalid_id(1111111));
-
-        Ok(())
+    let cli = Cli::parse();
+    common::cli::init_logger(cli.args.verbose);
+
+    let day = Day02 {
+        radix: cli.radix,
+        strict: cli.strict,
+        threads: cli
+            .threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get),
+    };
+    let input = common::cli::read_input::<Day02>(&cli.args)?;
+
+    if let Some(format) = cli.report {
+        let reports = day.parse(input)?.report(cli.policy);
+        return match format {
+            ReportFormat::Table => report::write_table(std::io::stdout().lock(), &reports),
+            ReportFormat::Json => output::print_json(&reports),
+        };
     }
 
-    #[test]
-    fn example() -> Result<()> {
-        let expected = vec![2, 2, 2, 1, 1, 0, 1, 1, 1, 1, 1];
-        let ranges = Ranges::try_from(aoc_util::init_test()?)?;
-
-        for (expected, range) in expected.into_iter().zip(&ranges.0) {
-            assert_eq!(expected, range.invalid_ids().len());
-        }
-
-        assert_eq!(4174379265, ranges.invalid_id_sum());
-
-        Ok(())
-    }
+    common::cli::solve(&day, &cli.args, input)
 }
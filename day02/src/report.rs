@@ -0,0 +1,118 @@
+//! Which IDs in each of the [`Ranges`] are invalid, and why.
+
+use crate::{InvalidIdPolicy, Ranges, format_id};
+use anyhow::Result;
+use serde::Serialize;
+use std::io::Write;
+
+/// An invalid ID, written in the input's radix, and the pattern repeated to make it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct InvalidId {
+    pub id: String,
+    pub pattern: String,
+    pub repeats: usize,
+}
+
+/// Every invalid ID in one of the input ranges.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RangeReport {
+    /// The range as `start-end`, written in the input's radix.
+    pub range: String,
+    pub invalid_ids: Vec<InvalidId>,
+}
+
+impl Ranges {
+    /// For each range, in input order, the IDs which are invalid under `policy` and the pattern
+    /// which makes each one invalid.
+    pub fn report(&self, policy: InvalidIdPolicy) -> Vec<RangeReport> {
+        self.ranges
+            .iter()
+            .map(|range| RangeReport {
+                range: format!(
+                    "{}-{}",
+                    format_id(range.0, self.radix.get()),
+                    format_id(range.1, self.radix.get())
+                ),
+                invalid_ids: range
+                    .invalid_ids(policy, self.radix)
+                    .into_iter()
+                    .map(|id| {
+                        let (pattern, repeats) = policy
+                            .explain(id, self.radix)
+                            .expect("Enumerated IDs are invalid");
+                        InvalidId {
+                            id: format_id(id, self.radix.get()),
+                            pattern,
+                            repeats,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Writes `reports` as a table with a row per invalid ID, or a `-` for ranges without any.
+pub fn write_table<W: Write>(mut writer: W, reports: &[RangeReport]) -> Result<()> {
+    let width = reports.iter().map(|r| r.range.len()).max().unwrap_or(0);
+    writeln!(writer, "{:<width$}  Invalid IDs", "Range")?;
+
+    for report in reports {
+        if report.invalid_ids.is_empty() {
+            writeln!(writer, "{:<width$}  -", report.range)?;
+        }
+
+        for invalid_id in report.invalid_ids.iter() {
+            writeln!(
+                writer,
+                "{:<width$}  {} = \"{}\" × {}",
+                report.range, invalid_id.id, invalid_id.pattern, invalid_id.repeats
+            )?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() -> Result<()> {
+        let ranges = Ranges::try_from(vec!["95-115,121200-121300,1698522-1698528".to_string()])?;
+        let reports = ranges.report(InvalidIdPolicy::AtLeastTwice);
+
+        assert_eq!(
+            vec!["95-115", "121200-121300", "1698522-1698528"],
+            reports.iter().map(|r| r.range.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            InvalidId {
+                id: "121212".to_string(),
+                pattern: "12".to_string(),
+                repeats: 3
+            },
+            reports[1].invalid_ids[0]
+        );
+
+        let mut table = Vec::new();
+        write_table(&mut table, &reports)?;
+        assert_eq!(
+            "Range            Invalid IDs\n\
+             95-115           99 = \"9\" × 2\n\
+             95-115           111 = \"1\" × 3\n\
+             121200-121300    121212 = \"12\" × 3\n\
+             1698522-1698528  -\n",
+            String::from_utf8(table)?
+        );
+
+        assert_eq!(
+            r#"{"range":"95-115","invalid_ids":[{"id":"99","pattern":"9","repeats":2},{"id":"111","pattern":"1","repeats":3}]}"#,
+            serde_json::to_string(&reports[0])?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+//! Day 3: Lobby.
+//!
+//! Each [`Bank`] of batteries is a line of digits; switching some of them on, in order, produces
+//! a joltage.
+
+use anyhow::{Result, anyhow, bail};
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// A bank of batteries, one joltage digit per battery.
+#[derive(Debug)]
+pub struct Bank(String);
+
+impl FromStr for Bank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(column) = s.chars().position(|c| !c.is_ascii_digit()) {
+            return Err(ParseError::new(s, column + 1, "a joltage digit"));
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+/// The batteries switched on in a [`Bank`], and the joltage they produce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Joltage {
+    pub value: u128,
+    /// 0-based positions of the switched on batteries, in order.
+    pub indices: Vec<usize>,
+}
+
+impl Bank {
+    /// The largest joltage formed by switching on exactly `k` batteries, keeping their order, or
+    /// `None` if the bank has fewer than `k` batteries. Fails if the joltage doesn't fit in a
+    /// `u128`.
+    pub fn largest_joltage(&self, k: usize) -> Result<Option<Joltage>> {
+        let digits = self.0.as_bytes();
+        let n = digits.len();
+
+        if n < k {
+            return Ok(None);
+        }
+
+        let mut joltage = Joltage {
+            value: 0,
+            indices: Vec::with_capacity(k),
+        };
+        let mut start = 0;
+
+        for remaining in (0..k).rev() {
+            // Leave enough batteries after this one to switch on the rest, and take the earliest
+            // of the largest digits so that as many as possible are left to choose from
+            let end = n - remaining;
+            let chosen = (start..end)
+                .rev()
+                .max_by_key(|&i| digits[i])
+                .expect("At least one battery to choose from");
+
+            let digit = (digits[chosen] - b'0') as u128;
+            joltage.value = joltage
+                .value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or_else(|| anyhow!("Joltage from {k} batteries doesn't fit in a u128"))?;
+            joltage.indices.push(chosen);
+            start = chosen + 1;
+        }
+
+        Ok(Some(joltage))
+    }
+}
+
+/// The puzzle input: one [`Bank`] per line.
+#[derive(Debug)]
+pub struct Banks(pub Vec<Bank>);
+
+impl Banks {
+    /// Sums the value of every bank's [`Bank::largest_joltage`] from `k` batteries. Fails if any
+    /// bank has fewer than `k` batteries, or if any joltage or the sum doesn't fit in a `u128`.
+    pub fn total_joltage(&self, k: usize) -> Result<u128> {
+        let mut total: u128 = 0;
+        for (i, bank) in self.0.iter().enumerate() {
+            let Some(joltage) = bank.largest_joltage(k)? else {
+                bail!(
+                    "Bank on line {} has {} batteries, fewer than {k}",
+                    i + 1,
+                    bank.0.len()
+                );
+            };
+            total = total
+                .checked_add(joltage.value)
+                .ok_or_else(|| anyhow!("Total joltage doesn't fit in a u128"))?;
+        }
+
+        Ok(total)
+    }
+}
+
+impl TryFrom<Vec<String>> for Banks {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        Ok(Self(parse::parse_lines(&value, Bank::from_str)?))
+    }
+}
+
+/// [`Solution`] for day 3.
+#[derive(Debug, Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Banks;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Banks::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.total_joltage(2)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.total_joltage(12)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn largest_joltage(bank: &str, k: usize) -> Result<u128> {
+        Ok(Bank::from_str(bank)?.largest_joltage(k)?.unwrap().value)
+    }
+
+    #[test]
+    fn example_part1() -> Result<()> {
+        assert_eq!(98, largest_joltage("987654321111111", 2)?);
+        assert_eq!(89, largest_joltage("811111111111119", 2)?);
+        assert_eq!(78, largest_joltage("234234234234278", 2)?);
+        assert_eq!(92, largest_joltage("818181911112111", 2)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        assert_eq!(987654321111, largest_joltage("987654321111111", 12)?);
+        assert_eq!(811111111119, largest_joltage("811111111111119", 12)?);
+        assert_eq!(434234234278, largest_joltage("234234234234278", 12)?);
+        assert_eq!(888911112111, largest_joltage("818181911112111", 12)?);
+
+        let banks = Banks::try_from(aoc_util::init_test()?)?;
+        assert_eq!(357, banks.total_joltage(2)?);
+        assert_eq!(3121910778619, banks.total_joltage(12)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn indices() -> Result<()> {
+        let bank = Bank::from_str("818181911112111")?;
+
+        assert_eq!(
+            Some(Joltage {
+                value: 92,
+                indices: vec![6, 11]
+            }),
+            bank.largest_joltage(2)?
+        );
+        assert_eq!(
+            vec![0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+            bank.largest_joltage(12)?.unwrap().indices
+        );
+
+        // Every battery, or none
+        assert_eq!(
+            (0..15).collect::<Vec<_>>(),
+            bank.largest_joltage(15)?.unwrap().indices
+        );
+        assert_eq!(Some(0), bank.largest_joltage(0)?.map(|j| j.value));
+        assert_eq!(None, bank.largest_joltage(16)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        // 38 nines fit in a `u128`, but 39 or more don't
+        let nines = Bank::from_str(&"9".repeat(45))?;
+        assert_eq!(
+            Some(10u128.pow(38) - 1),
+            nines.largest_joltage(38)?.map(|j| j.value)
+        );
+        assert!(nines.largest_joltage(40).is_err());
+
+        let banks = Banks(vec![nines]);
+        assert!(banks.total_joltage(40).is_err());
+
+        // Each joltage fits, but their sum doesn't
+        let banks = Banks(vec![
+            Bank::from_str(&"3".repeat(39))?,
+            Bank::from_str(&"3".repeat(39))?,
+        ]);
+        assert!(banks.total_joltage(39).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_few_batteries() -> Result<()> {
+        let banks = Banks::try_from(vec!["987".to_string(), "12".to_string()])?;
+        assert_eq!(98 + 12, banks.total_joltage(2)?);
+        assert_eq!(
+            "Bank on line 2 has 2 batteries, fewer than 3",
+            banks.total_joltage(3).unwrap_err().to_string()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 4: Printing Department.
+//!
+//! Rolls of paper (`@`) on a [`Floor`] can be reached by a forklift when fewer than four of their
+//! neighbours are also rolls.
+
+use anyhow::{Result, bail};
+use aoc_util::grid::Grid;
+use aoc_util::math::two_dimensional::Point;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::fmt::Debug;
+
+/// The puzzle input: a grid which is `true` wherever there is a roll of paper.
+#[derive(Debug, Clone)]
+pub struct Floor(Grid<bool>);
+
+impl TryFrom<Vec<String>> for Floor {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        let mut grid = Vec::new();
+
+        for (y, row) in value.iter().enumerate() {
+            let mut grid_row = Vec::new();
+            for (x, col) in row.chars().enumerate() {
+                grid_row.push(match col {
+                    '@' => true,
+                    '.' => false,
+                    _ => {
+                        bail!(ParseError::new(row, x + 1, "`@` or `.`").on_line(y + 1))
+                    }
+                });
+            }
+            grid.push(grid_row);
+        }
+
+        Ok(Self(Grid::try_from(grid)?))
+    }
+}
+
+impl Floor {
+    /// Whether there is a roll at `position` with fewer than four adjacent rolls.
+    pub fn is_roll_accessible(&self, position: Point) -> bool {
+        if !self.0[position] {
+            trace!("No roll at {position:?}");
+            return false;
+        }
+
+        let adj_count = aoc_util::grid::neighbors(&self.0, position, true)
+            .into_iter()
+            .filter(|n| {
+                let neighbor_is_roll = self.0[n.position];
+                trace!("Neighbor at {position:?} is roll: {neighbor_is_roll}");
+                neighbor_is_roll
+            })
+            .count();
+
+        debug!("{position:?} has {adj_count} adjacent roll(s)");
+        adj_count < 4
+    }
+
+    /// The positions of every roll which is currently accessible.
+    pub fn accessible_rolls(&self) -> Vec<Point> {
+        let mut accessible_rolls = Vec::new();
+
+        for y in 0..self.0.height() {
+            for x in 0..self.0.width() {
+                let position = Point::new(x, y);
+                if self.is_roll_accessible(position) {
+                    accessible_rolls.push(position);
+                }
+            }
+        }
+
+        accessible_rolls
+    }
+
+    /// How many rolls are currently accessible.
+    pub fn accessible_roll_count(&self) -> usize {
+        self.accessible_rolls().len()
+    }
+
+    /// Removes every currently accessible roll, returning how many were removed.
+    pub fn remove_accessible_rolls(&mut self) -> usize {
+        let to_remove = self.accessible_rolls();
+
+        to_remove
+            .iter()
+            .for_each(|&position| self.0[position] = false);
+
+        to_remove.len()
+    }
+
+    /// Repeatedly removes accessible rolls until none remain accessible, returning how many were
+    /// removed in total.
+    pub fn removable_roll_count(&mut self) -> usize {
+        let mut count = 0;
+
+        loop {
+            let accessible_rolls = self.remove_accessible_rolls();
+            count += accessible_rolls;
+
+            if accessible_rolls == 0 {
+                break;
+            }
+        }
+
+        count
+    }
+}
+
+/// [`Solution`] for day 4.
+#[derive(Debug, Default)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Floor;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Floor::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.accessible_roll_count().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.clone().removable_roll_count().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let mut grid = Floor::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(13, grid.accessible_roll_count());
+        assert_eq!(43, grid.removable_roll_count());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+//! Day 5: Cafeteria.
+//!
+//! The kitchen's [`Database`] lists ranges of fresh ingredient IDs, followed by the IDs of the
+//! available ingredients.
+
+use anyhow::Result;
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// The puzzle input: fresh ID ranges, a blank line, then available ingredient IDs.
+#[derive(Debug, Default)]
+pub struct Database {
+    fresh_ranges: Vec<RangeInclusive<usize>>,
+    ingredients: HashSet<usize>,
+}
+
+impl Database {
+    /// Whether `ingredient` falls within any of the fresh ranges.
+    pub fn is_fresh(&self, ingredient: usize) -> bool {
+        for range in self.fresh_ranges.iter() {
+            if range.contains(&ingredient) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// How many of the available ingredients are fresh.
+    pub fn fresh_count(&self) -> usize {
+        self.ingredients
+            .iter()
+            .filter(|i| self.is_fresh(**i))
+            .count()
+    }
+
+    /// The fresh ranges, sorted and with overlapping or adjacent ranges merged.
+    pub fn merged_ranges(&self) -> Vec<RangeInclusive<usize>> {
+        let mut fresh_ranges = self.fresh_ranges.clone();
+        fresh_ranges.sort_by(|a, b| a.start().cmp(b.start()).then(a.end().cmp(b.end())));
+
+        // Merge overlapping or adjacent ranges
+        let mut merged_ranges: Vec<RangeInclusive<usize>> = Vec::new();
+        for range in fresh_ranges {
+            if let Some(last) = merged_ranges.last_mut() {
+                // Check if current range overlaps or is adjacent to the last merged range
+                if *range.start() <= *last.end() + 1 {
+                    // Merge by extending the end if needed
+                    let new_end = (*last.end()).max(*range.end());
+                    *last = *last.start()..=new_end;
+                } else {
+                    // No overlap, add as new range
+                    merged_ranges.push(range);
+                }
+            } else {
+                // First range
+                merged_ranges.push(range);
+            }
+        }
+
+        merged_ranges
+    }
+
+    /// How many distinct IDs the fresh ranges cover.
+    pub fn fresh_id_count(&self) -> usize {
+        let mut fresh_id_count = 0;
+
+        for range in self.merged_ranges() {
+            fresh_id_count += range.end() - range.start() + 1;
+        }
+
+        fresh_id_count
+    }
+}
+
+impl TryFrom<Vec<String>> for Database {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        let mut database = Database::default();
+
+        let mut ranges = true;
+        for (i, line) in value.iter().enumerate() {
+            if line.is_empty() {
+                ranges = false;
+                continue;
+            }
+
+            let on_line = |e: ParseError| e.on_line(i + 1);
+            if ranges {
+                let (start, end) = line
+                    .split_once('-')
+                    .ok_or_else(|| ParseError::at_end(line, "`-`"))
+                    .map_err(on_line)?;
+
+                database.fresh_ranges.push(RangeInclusive::new(
+                    parse::parse_token(line, start, "a start ID").map_err(on_line)?,
+                    parse::parse_token(line, end, "an end ID").map_err(on_line)?,
+                ));
+            } else {
+                database
+                    .ingredients
+                    .insert(parse::parse_token(line, line, "an ingredient ID").map_err(on_line)?);
+            }
+        }
+
+        Ok(database)
+    }
+}
+
+/// [`Solution`] for day 5.
+#[derive(Debug, Default)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Database;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Database::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.fresh_count().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.fresh_id_count().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let db = Database::try_from(aoc_util::init_test()?)?;
+
+        assert!(!db.is_fresh(1));
+        assert!(db.is_fresh(5));
+        assert!(!db.is_fresh(8));
+        assert!(db.is_fresh(11));
+        assert!(db.is_fresh(17));
+        assert!(!db.is_fresh(32));
+
+        assert_eq!(3, db.fresh_count());
+        assert_eq!(14, db.fresh_id_count());
+
+        Ok(())
+    }
+}
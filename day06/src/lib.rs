@@ -0,0 +1,292 @@
+//! Day 6: Trash Compactor.
+//!
+//! A cephalopod's math [`Worksheet`] holds a row of problems, each a column of operands above an
+//! operator.
+
+use anyhow::{Result, bail};
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use log::trace;
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn apply(&self, operands: &[usize]) -> usize {
+        trace!("{self:?} -> {operands:?}");
+
+        match self {
+            Operator::Add => operands.iter().sum::<usize>(),
+            Operator::Multiply => operands.iter().product::<usize>(),
+        }
+    }
+}
+
+impl Debug for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Operator::Add => "+",
+                Operator::Multiply => "*",
+            }
+        )
+    }
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "+" => Self::Add,
+            "*" => Self::Multiply,
+            _ => return Err(ParseError::new(s, 1, "`+` or `*`")),
+        })
+    }
+}
+
+/// The puzzle input: rows of operands, then a row of operators.
+#[derive(Debug, Default)]
+pub struct Worksheet {
+    operands: Vec<Vec<usize>>,
+    operators: Vec<Operator>,
+    cephalopod_operands: Vec<Vec<usize>>,
+}
+
+impl Worksheet {
+    /// The result of the problem at `index`, reading each row as an operand.
+    pub fn apply_at(&self, index: usize) -> usize {
+        let column: Vec<usize> = self.operands.iter().map(|row| row[index]).collect();
+        self.operators[index].apply(&column)
+    }
+
+    /// Sums the result of every problem, reading each row as an operand.
+    pub fn grand_total(&self) -> usize {
+        (0..self.operators.len()).map(|i| self.apply_at(i)).sum()
+    }
+
+    /// The result of the problem at `index`, reading each column as an operand.
+    pub fn cephalopod_apply_at(&self, index: usize) -> usize {
+        self.operators[index].apply(&self.cephalopod_operands[index])
+    }
+
+    /// Sums the result of every problem, reading each column as an operand.
+    pub fn cephalopod_grand_total(&self) -> usize {
+        (0..self.operators.len())
+            .map(|i| self.cephalopod_apply_at(i))
+            .sum()
+    }
+
+    /// Cephalopod math is written right-to-left, one number per column with its most significant
+    /// digit at the top. Problems are separated by columns containing only whitespace.
+    fn read_cephalopod_operands(lines: &[String]) -> Result<Vec<Vec<usize>>, ParseError> {
+        let rows: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut problems = Vec::new();
+        let mut operands = Vec::new();
+
+        for x in (0..width).rev() {
+            let digits: String = rows
+                .iter()
+                .filter_map(|row| row.get(x))
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect();
+
+            if digits.is_empty() {
+                if !operands.is_empty() {
+                    problems.push(std::mem::take(&mut operands));
+                }
+            } else {
+                operands.push(
+                    digits
+                        .parse::<usize>()
+                        .map_err(|_| Self::cephalopod_error(lines, &rows, x))?,
+                );
+            }
+        }
+
+        if !operands.is_empty() {
+            problems.push(operands);
+        }
+
+        // Problems were read right-to-left, operators are stored left-to-right
+        problems.reverse();
+
+        Ok(problems)
+    }
+
+    /// Points at the first character in column `x` which isn't a digit, or at the top of the
+    /// column if it's all digits but too many of them.
+    fn cephalopod_error(lines: &[String], rows: &[Vec<char>], x: usize) -> ParseError {
+        let column = |y: usize| rows[y].get(x).filter(|c| !c.is_ascii_whitespace());
+
+        let (y, expected) =
+            match (0..rows.len()).find(|&y| column(y).is_some_and(|c| !c.is_ascii_digit())) {
+                Some(y) => (y, "a digit"),
+                None => (
+                    (0..rows.len()).find(|&y| column(y).is_some()).unwrap_or(0),
+                    "a column of digits which fits in a `usize`",
+                ),
+            };
+
+        ParseError::new(&lines[y], x + 1, expected).on_line(y + 1)
+    }
+}
+
+impl TryFrom<Vec<String>> for Worksheet {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        let mut worksheet = Worksheet::default();
+
+        if value.is_empty() {
+            bail!("Expected lines of operands followed by a line of operators");
+        }
+
+        let mut count = None;
+
+        for (i, line) in value.iter().enumerate().take(value.len() - 1) {
+            let mut operands = Vec::new();
+            for v in line.split_ascii_whitespace() {
+                operands
+                    .push(parse::parse_token(line, v, "an operand").map_err(|e| e.on_line(i + 1))?);
+            }
+
+            if let Some(count) = count {
+                if count != operands.len() {
+                    bail!(
+                        ParseError::at_end(
+                            line,
+                            format!("{count} operands, found {}", operands.len())
+                        )
+                        .on_line(i + 1)
+                    );
+                }
+            } else {
+                count = Some(operands.len());
+            }
+
+            worksheet.operands.push(operands);
+        }
+
+        let line = &value[value.len() - 1];
+        let on_line = |e: ParseError| e.on_line(value.len());
+
+        let mut operators = Vec::new();
+        for o in line.split_ascii_whitespace() {
+            operators.push(
+                Operator::from_str(o)
+                    .map_err(|e| e.within(line, o))
+                    .map_err(on_line)?,
+            );
+        }
+
+        if let Some(count) = count
+            && count != operators.len()
+        {
+            bail!(on_line(ParseError::at_end(
+                line,
+                format!("{count} operators, found {}", operators.len()),
+            )));
+        }
+
+        worksheet.operators = operators;
+
+        worksheet.cephalopod_operands = Self::read_cephalopod_operands(&value[..value.len() - 1])?;
+        let problems = worksheet.cephalopod_operands.len();
+        if problems != worksheet.operators.len() {
+            bail!(on_line(ParseError::at_end(
+                line,
+                format!(
+                    "{problems} operators, one per problem read by column, found {}",
+                    worksheet.operators.len()
+                ),
+            )));
+        }
+
+        Ok(worksheet)
+    }
+}
+
+/// [`Solution`] for day 6.
+#[derive(Debug, Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Worksheet::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.grand_total().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.cephalopod_grand_total().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let worksheet = Worksheet::try_from(aoc_util::init_test()?)?;
+
+        let expected = vec![33210, 490, 4243455, 401];
+
+        for (i, expected) in expected.into_iter().enumerate() {
+            assert_eq!(expected, worksheet.apply_at(i));
+        }
+
+        let expected = vec![8544, 625, 3253600, 1058];
+
+        for (i, expected) in expected.into_iter().enumerate() {
+            assert_eq!(expected, worksheet.cephalopod_apply_at(i));
+        }
+
+        assert_eq!(3263827, worksheet.cephalopod_grand_total());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cephalopod_errors() {
+        let parse = |lines: &[&str]| {
+            Worksheet::try_from(lines.iter().map(|l| l.to_string()).collect::<Vec<_>>())
+                .unwrap_err()
+                .to_string()
+        };
+
+        // Fine as rows of numbers, but `+` isn't a digit when read by column
+        assert_eq!(
+            "line 2, column 3: expected a digit\n  1 +5\n    ^",
+            parse(&["12 3", "1 +5", "*  +"])
+        );
+
+        // 21 digits don't fit, even though each row's operand does
+        let tall: Vec<&str> = std::iter::repeat_n("9", 21).chain(["+"]).collect();
+        assert_eq!(
+            "line 1, column 1: expected a column of digits which fits in a `usize`\n  9\n  ^",
+            parse(&tall)
+        );
+
+        // No blank column separates the two problems
+        assert_eq!(
+            "line 3, column 5: expected 1 operators, one per problem read by column, found 2\n  *  +\n      ^",
+            parse(&["12 3", "1 23", "*  +"])
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Day 7: Laboratories.
+//!
+//! A tachyon beam enters the [`Manifolds`] at `S` and travels down, splitting left and right
+//! whenever it hits a splitter (`^`).
+
+use anyhow::{Result, bail};
+use common::{Answer, ParseError, Solution};
+use log::trace;
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(Copy, Clone)]
+enum Manifold {
+    Start,
+    Splitter,
+    Beam,
+    Empty,
+}
+
+impl TryFrom<char> for Manifold {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'S' => Self::Start,
+            '^' => Self::Splitter,
+            '|' => Self::Beam,
+            '.' => Self::Empty,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl Debug for Manifold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for Manifold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Start => 'S',
+                Self::Splitter => '^',
+                Self::Beam => '|',
+                Self::Empty => '.',
+            }
+        )
+    }
+}
+
+/// The puzzle input: a diagram of the tachyon manifold, one row per line.
+#[derive(Debug, Default, Clone)]
+pub struct Manifolds {
+    manifolds: Vec<Vec<Manifold>>,
+    index: usize,
+    splits: usize,
+}
+
+impl Manifolds {
+    fn advance(mut self) -> Result<Self, Self> {
+        let index = self.index;
+
+        if index > self.manifolds.len() - 1 {
+            return Err(self);
+        }
+
+        let len = self.manifolds[index].len();
+        for i in 0..len {
+            match self.manifolds[index][i] {
+                Manifold::Splitter => {
+                    if index > 0 && matches!(self.manifolds[index - 1][i], Manifold::Beam) {
+                        if i > 0 {
+                            self.manifolds[index][i - 1] = Manifold::Beam;
+                        }
+
+                        if i + 1 < len {
+                            self.manifolds[index][i + 1] = Manifold::Beam;
+                        }
+
+                        trace!("Splitting at {i}, {index}");
+                        self.splits += 1;
+                    }
+                }
+                Manifold::Empty => {
+                    if index > 0
+                        && matches!(
+                            self.manifolds[index - 1][i],
+                            Manifold::Start | Manifold::Beam
+                        )
+                    {
+                        self.manifolds[index][i] = Manifold::Beam
+                    }
+                }
+                Manifold::Start | Manifold::Beam => {}
+            }
+        }
+
+        self.index += 1;
+
+        Ok(self)
+    }
+
+    /// How many distinct paths a single tachyon particle could take through the manifold.
+    pub fn timelines(&self) -> usize {
+        let Some(first) = self.manifolds.first() else {
+            return 0;
+        };
+
+        // Number of timelines in which a beam occupies each column of the current row
+        let mut timelines: Vec<usize> = first
+            .iter()
+            .map(|m| usize::from(matches!(m, Manifold::Start)))
+            .collect();
+
+        for (index, row) in self.manifolds.iter().enumerate().skip(1) {
+            let mut next = vec![0; row.len()];
+
+            for (i, &count) in timelines.iter().enumerate().take(row.len()) {
+                if count == 0 {
+                    continue;
+                }
+
+                if matches!(row[i], Manifold::Splitter) {
+                    trace!("Splitting {count} timeline(s) at {i}, {index}");
+
+                    if i > 0 {
+                        next[i - 1] += count;
+                    }
+
+                    if i + 1 < row.len() {
+                        next[i + 1] += count;
+                    }
+                } else {
+                    next[i] += count;
+                }
+            }
+
+            timelines = next;
+        }
+
+        timelines.iter().sum()
+    }
+
+    /// Runs the beam through the whole manifold, returning how many times it was split.
+    pub fn simulate(self) -> usize {
+        let mut manifolds = self;
+        loop {
+            match manifolds.advance() {
+                Ok(m) => manifolds = m,
+                Err(m) => {
+                    return m.splits;
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<Vec<String>> for Manifolds {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        let mut manifolds = Manifolds::default();
+
+        for (y, line) in value.iter().enumerate() {
+            let mut manifold_line = Vec::new();
+
+            for (x, c) in line.chars().enumerate() {
+                let Ok(manifold) = Manifold::try_from(c) else {
+                    bail!(ParseError::new(line, x + 1, "`S`, `^`, `|` or `.`").on_line(y + 1));
+                };
+                manifold_line.push(manifold);
+            }
+
+            manifolds.manifolds.push(manifold_line);
+        }
+
+        Ok(manifolds)
+    }
+}
+
+/// [`Solution`] for day 7.
+#[derive(Debug, Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Manifolds;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Manifolds::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.clone().simulate().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.timelines().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let manifolds = Manifolds::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(40, manifolds.timelines());
+        assert_eq!(21, manifolds.simulate());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+//! Day 8: Playground.
+//!
+//! Strings of lights connect [`JunctionBox`]es into circuits, always joining the closest pair of
+//! boxes which haven't been connected yet.
+
+use anyhow::Result;
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use log::debug;
+use std::str::FromStr;
+
+/// A junction box's position, written as `x,y,z`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct JunctionBox {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl JunctionBox {
+    /// The square of the straight-line distance to `other`.
+    pub fn distance_squared(&self, other: &JunctionBox) -> usize {
+        self.x.abs_diff(other.x).pow(2)
+            + self.y.abs_diff(other.y).pow(2)
+            + self.z.abs_diff(other.z).pow(2)
+    }
+}
+
+impl FromStr for JunctionBox {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coordinates = s.splitn(3, ',');
+        let mut next = |expected| match coordinates.next() {
+            Some(token) => parse::parse_token(s, token, expected),
+            None => Err(ParseError::at_end(s, "`,`")),
+        };
+
+        Ok(Self {
+            x: next("an x coordinate")?,
+            y: next("a y coordinate")?,
+            z: next("a z coordinate")?,
+        })
+    }
+}
+
+/// Disjoint sets of junction boxes, tracking the size of each circuit.
+struct Circuits {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl Circuits {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            // Path halving
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Joins the circuits containing `a` and `b`, returning `false` if they were already joined.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    fn sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|i| self.size[i])
+            .collect()
+    }
+}
+
+/// The puzzle input: one [`JunctionBox`] per line.
+#[derive(Debug)]
+pub struct Playground(pub Vec<JunctionBox>);
+
+impl Playground {
+    /// Every pair of junction box indices, closest first.
+    fn closest_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::with_capacity(self.0.len() * self.0.len().saturating_sub(1) / 2);
+        for i in 0..self.0.len() {
+            for j in i + 1..self.0.len() {
+                pairs.push((self.0[i].distance_squared(&self.0[j]), i, j));
+            }
+        }
+
+        pairs.sort_unstable();
+        pairs.into_iter().map(|(_, i, j)| (i, j)).collect()
+    }
+
+    /// Connects the `connections` closest pairs, then multiplies together the sizes of the three
+    /// largest circuits.
+    pub fn largest_circuits_product(&self, connections: usize) -> usize {
+        let mut circuits = Circuits::new(self.0.len());
+        for (i, j) in self.closest_pairs().into_iter().take(connections) {
+            circuits.union(i, j);
+        }
+
+        let mut sizes = circuits.sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        debug!("Circuit sizes: {sizes:?}");
+
+        sizes.iter().take(3).product()
+    }
+
+    /// The pair of junction boxes whose connection first joins every box into a single circuit,
+    /// or `None` if there are fewer than two boxes.
+    pub fn final_connection(&self) -> Option<(JunctionBox, JunctionBox)> {
+        let mut circuits = Circuits::new(self.0.len());
+        for (i, j) in self.closest_pairs() {
+            if circuits.union(i, j) && circuits.count == 1 {
+                return Some((self.0[i], self.0[j]));
+            }
+        }
+
+        None
+    }
+}
+
+impl TryFrom<Vec<String>> for Playground {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self> {
+        Ok(Self(parse::parse_lines(&value, JunctionBox::from_str)?))
+    }
+}
+
+/// [`Solution`] for day 8, connecting `connections` pairs of junction boxes in part 1.
+#[derive(Debug)]
+pub struct Day08 {
+    pub connections: usize,
+}
+
+impl Default for Day08 {
+    fn default() -> Self {
+        Self { connections: 1000 }
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Playground::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.largest_circuits_product(self.connections).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let (a, b) = input
+            .final_connection()
+            .ok_or_else(|| anyhow::anyhow!("Need at least two junction boxes"))?;
+        Ok((a.x * b.x).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let playground = Playground::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(40, playground.largest_circuits_product(10));
+
+        let (a, b) = playground.final_connection().unwrap();
+        assert_eq!(
+            JunctionBox {
+                x: 216,
+                y: 146,
+                z: 977
+            },
+            a
+        );
+        assert_eq!(
+            JunctionBox {
+                x: 117,
+                y: 168,
+                z: 530
+            },
+            b
+        );
+        assert_eq!(
+            Answer::from(25272usize),
+            Day08 { connections: 10 }.part2(&playground)?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+use day08::Day08;
+
+fn main() -> Result<()> {
+    common::main::<Day08>()
+}
@@ -0,0 +1,186 @@
+//! Day 9: Movie Theater.
+//!
+//! Red tiles on the theater [`Floor`] can be used as opposite corners of a rectangle. Listed in
+//! order, they also form a loop of red and green tiles.
+
+use anyhow::Result;
+use aoc_util::math::two_dimensional::Point;
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use std::collections::VecDeque;
+
+/// The puzzle input: the position of each red tile, one `x,y` per line.
+#[derive(Debug, Clone)]
+pub struct Floor(pub Vec<Point>);
+
+impl TryFrom<Vec<String>> for Floor {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self> {
+        let points = parse::parse_lines(&value, |line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::at_end(line, "`,`"))?;
+            Ok(Point::new(
+                parse::parse_token(line, x, "an x coordinate")?,
+                parse::parse_token(line, y, "a y coordinate")?,
+            ))
+        })?;
+
+        Ok(Floor(points))
+    }
+}
+
+impl Floor {
+    /// The area of the largest rectangle with red tiles in two opposite corners.
+    pub fn largest_rectangle(&self) -> usize {
+        // Find the largest rectangle by checking all pairs of points
+        let mut max_area = 0;
+        for i in 0..self.0.len() {
+            let p1 = self.0[i];
+
+            for j in (i + 1)..self.0.len() {
+                let p2 = self.0[j];
+
+                let width = p1.x.abs_diff(p2.x) + 1;
+                let height = p1.y.abs_diff(p2.y) + 1;
+
+                let area = width * height;
+                max_area = max_area.max(area);
+            }
+        }
+
+        max_area
+    }
+
+    /// The area of the largest rectangle with red tiles in two opposite corners, which only
+    /// contains red or green tiles.
+    pub fn largest_inner_rectangle(&self) -> usize {
+        // Compress coordinates so that each distinct x/y maps to an odd index, leaving an even
+        // index between neighbours to represent the (possibly wide) gap between them.
+        let mut xs: Vec<usize> = self.0.iter().map(|p| p.x).collect();
+        xs.sort_unstable();
+        xs.dedup();
+
+        let mut ys: Vec<usize> = self.0.iter().map(|p| p.y).collect();
+        ys.sort_unstable();
+        ys.dedup();
+
+        let compress = |p: Point| {
+            (
+                2 * xs.binary_search(&p.x).unwrap() + 1,
+                2 * ys.binary_search(&p.y).unwrap() + 1,
+            )
+        };
+
+        let width = 2 * xs.len() + 1;
+        let height = 2 * ys.len() + 1;
+
+        // Draw the loop of red and green tiles
+        let mut boundary = vec![vec![false; width]; height];
+        for (i, &p1) in self.0.iter().enumerate() {
+            let p2 = self.0[(i + 1) % self.0.len()];
+
+            let (x1, y1) = compress(p1);
+            let (x2, y2) = compress(p2);
+
+            for row in boundary.iter_mut().take(y1.max(y2) + 1).skip(y1.min(y2)) {
+                for cell in row.iter_mut().take(x1.max(x2) + 1).skip(x1.min(x2)) {
+                    *cell = true;
+                }
+            }
+        }
+
+        // Flood fill from a corner, which is always outside the loop
+        let mut outside = vec![vec![false; width]; height];
+        let mut queue = VecDeque::from([(0usize, 0usize)]);
+        outside[0][0] = true;
+
+        while let Some((x, y)) = queue.pop_front() {
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+
+            for (nx, ny) in neighbors {
+                if nx < width && ny < height && !boundary[ny][nx] && !outside[ny][nx] {
+                    outside[ny][nx] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        // Prefix sums of outside cells, so a rectangle can be checked in constant time
+        let mut prefix = vec![vec![0usize; width + 1]; height + 1];
+        for y in 0..height {
+            for x in 0..width {
+                prefix[y + 1][x + 1] =
+                    usize::from(outside[y][x]) + prefix[y][x + 1] + prefix[y + 1][x] - prefix[y][x];
+            }
+        }
+
+        let outside_count = |(x1, y1): (usize, usize), (x2, y2): (usize, usize)| {
+            let (min_x, max_x) = (x1.min(x2), x1.max(x2) + 1);
+            let (min_y, max_y) = (y1.min(y2), y1.max(y2) + 1);
+
+            prefix[max_y][max_x] + prefix[min_y][min_x]
+                - prefix[min_y][max_x]
+                - prefix[max_y][min_x]
+        };
+
+        let mut max_area = 0;
+        for i in 0..self.0.len() {
+            let p1 = self.0[i];
+
+            for j in (i + 1)..self.0.len() {
+                let p2 = self.0[j];
+
+                let area = (p1.x.abs_diff(p2.x) + 1) * (p1.y.abs_diff(p2.y) + 1);
+                if area > max_area && outside_count(compress(p1), compress(p2)) == 0 {
+                    max_area = area;
+                }
+            }
+        }
+
+        max_area
+    }
+}
+
+/// [`Solution`] for day 9.
+#[derive(Debug, Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Floor;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Floor::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.largest_rectangle().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.largest_inner_rectangle().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let floor = Floor::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(50, floor.largest_rectangle());
+        assert_eq!(24, floor.largest_inner_rectangle());
+
+        Ok(())
+    }
+}
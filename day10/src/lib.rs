@@ -0,0 +1,498 @@
+//! Day 10: Factory.
+//!
+//! Each machine's [`Manual`] lists its indicator lights, the buttons wired to them, and its
+//! joltage requirements.
+
+use anyhow::Result;
+use common::parse::{self, ParseError};
+use common::{Answer, Solution, warnings};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The puzzle input: one [`Manual`] per line.
+#[derive(Debug, Default)]
+pub struct Manuals(pub Vec<Manual>);
+
+impl Manuals {
+    /// Sums [`Manual::min_presses`] across every machine, skipping with a warning any whose goal
+    /// can't be reached.
+    pub fn min_presses(&self) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| {
+                let presses = m.min_presses();
+                if presses.is_none() {
+                    warnings::warn(format!("Skipping manual {}: goal is unreachable", i + 1));
+                }
+                presses
+            })
+            .sum()
+    }
+
+    /// Sums [`Manual::min_joltage_presses`] across every machine, skipping with a warning any
+    /// whose requirements can't be met.
+    pub fn min_joltage_presses(&self) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| {
+                let presses = m.min_joltage_presses();
+                if presses.is_none() {
+                    warnings::warn(format!(
+                        "Skipping manual {}: joltage requirements are unreachable",
+                        i + 1
+                    ));
+                }
+                presses
+            })
+            .sum()
+    }
+}
+
+impl TryFrom<Vec<String>> for Manuals {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self> {
+        Ok(Manuals(parse::parse_lines(&value, Manual::from_str)?))
+    }
+}
+
+/// A single machine, written as e.g. `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+#[derive(Debug)]
+pub struct Manual {
+    goal: Vec<bool>,
+    wiring_schematics: Vec<Vec<usize>>,
+    joltage_requirements: Vec<usize>,
+}
+
+impl Manual {
+    /// The fewest button presses which turn on exactly the lights in the goal, or `None` if the
+    /// goal can't be reached.
+    pub fn min_presses(&self) -> Option<usize> {
+        let num_lights = self.goal.len();
+        let num_buttons = self.wiring_schematics.len();
+
+        // Build augmented matrix [A | b] where:
+        // - A[i][j] = 1 if button j toggles light i
+        // - b[i] = goal state for light i
+        let mut matrix = vec![vec![false; num_buttons + 1]; num_lights];
+
+        for (button_idx, button) in self.wiring_schematics.iter().enumerate() {
+            for &light_idx in button {
+                matrix[light_idx][button_idx] = true;
+            }
+        }
+
+        // Set goal column
+        for (i, &goal_state) in self.goal.iter().enumerate() {
+            matrix[i][num_buttons] = goal_state;
+        }
+
+        // Gaussian elimination over GF(2)
+        let pivots = self.gauss_eliminate(&mut matrix, num_buttons);
+
+        // Check if system is solvable
+        for row in &matrix {
+            // Check for contradiction: [0 0 0 ... 0 | 1]
+            let all_zero = row[..num_buttons].iter().all(|&x| !x);
+            if all_zero && row[num_buttons] {
+                return None;
+            }
+        }
+
+        // Find solution with minimum button presses
+        Some(self.find_min_solution(&matrix, &pivots, num_buttons))
+    }
+
+    /// The fewest button presses which raise every counter to exactly its joltage requirement.
+    pub fn min_joltage_presses(&self) -> Option<usize> {
+        let num_counters = self.joltage_requirements.len();
+
+        // Every combination of buttons pressed at most once, grouped by which counters it leaves
+        // odd. Any solution presses each button some number of times; the buttons pressed an odd
+        // number of times must fix the parity of each counter, and the rest of the presses come in
+        // pairs, which halves the remaining problem.
+        let mut combinations: HashMap<Vec<bool>, Vec<(Vec<usize>, usize)>> = HashMap::new();
+        for mask in 0..(1usize << self.wiring_schematics.len()) {
+            let mut effect = vec![0; num_counters];
+            for (button_idx, button) in self.wiring_schematics.iter().enumerate() {
+                if (mask >> button_idx) & 1 == 1 {
+                    for &counter in button {
+                        effect[counter] += 1;
+                    }
+                }
+            }
+
+            let parity = effect.iter().map(|e| e % 2 == 1).collect();
+            combinations
+                .entry(parity)
+                .or_default()
+                .push((effect, mask.count_ones() as usize));
+        }
+
+        Self::min_joltage_presses_for(
+            &self.joltage_requirements,
+            &combinations,
+            &mut HashMap::new(),
+        )
+    }
+
+    fn min_joltage_presses_for(
+        target: &[usize],
+        combinations: &HashMap<Vec<bool>, Vec<(Vec<usize>, usize)>>,
+        cache: &mut HashMap<Vec<usize>, Option<usize>>,
+    ) -> Option<usize> {
+        if target.iter().all(|&t| t == 0) {
+            return Some(0);
+        }
+
+        if let Some(&presses) = cache.get(target) {
+            return presses;
+        }
+
+        let parity: Vec<bool> = target.iter().map(|t| t % 2 == 1).collect();
+
+        let mut min_presses = None;
+        for (effect, presses) in combinations.get(&parity).into_iter().flatten() {
+            if effect.iter().zip(target).any(|(e, t)| e > t) {
+                continue;
+            }
+
+            let remaining: Vec<usize> = effect
+                .iter()
+                .zip(target)
+                .map(|(e, t)| (t - e) / 2)
+                .collect();
+
+            if let Some(remaining_presses) =
+                Self::min_joltage_presses_for(&remaining, combinations, cache)
+            {
+                let total = presses + 2 * remaining_presses;
+                min_presses = Some(min_presses.map_or(total, |m: usize| m.min(total)));
+            }
+        }
+
+        cache.insert(target.to_vec(), min_presses);
+        min_presses
+    }
+
+    fn gauss_eliminate(&self, matrix: &mut [Vec<bool>], num_cols: usize) -> Vec<Option<usize>> {
+        let num_rows = matrix.len();
+        let mut pivots = vec![None; num_rows];
+        let mut current_row = 0;
+
+        for col in 0..num_cols {
+            // Find pivot
+            let pivot_row = (current_row..num_rows).find(|&row| matrix[row][col]);
+
+            if let Some(pivot_row) = pivot_row {
+                // Swap rows if needed
+                if pivot_row != current_row {
+                    matrix.swap(pivot_row, current_row);
+                }
+
+                pivots[current_row] = Some(col);
+
+                // Eliminate all other rows (including above, for RREF)
+                for row in 0..num_rows {
+                    if row != current_row && matrix[row][col] {
+                        // XOR this row with current_row
+                        // Use split_at_mut to avoid multiple mutable borrows
+                        if row < current_row {
+                            let (top, bottom) = matrix.split_at_mut(current_row);
+                            for (a, &b) in top[row].iter_mut().zip(&bottom[0]).take(num_cols + 1) {
+                                *a ^= b;
+                            }
+                        } else {
+                            let (top, bottom) = matrix.split_at_mut(row);
+                            for (a, &b) in bottom[0]
+                                .iter_mut()
+                                .zip(&top[current_row])
+                                .take(num_cols + 1)
+                            {
+                                *a ^= b;
+                            }
+                        }
+                    }
+                }
+
+                current_row += 1;
+            }
+        }
+
+        pivots
+    }
+
+    fn find_min_solution(
+        &self,
+        matrix: &[Vec<bool>],
+        pivots: &[Option<usize>],
+        num_buttons: usize,
+    ) -> usize {
+        // Identify free variables (columns without pivots)
+        let pivot_cols: Vec<usize> = pivots.iter().filter_map(|&p| p).collect();
+        let mut free_vars = vec![];
+        for col in 0..num_buttons {
+            if !pivot_cols.contains(&col) {
+                free_vars.push(col);
+            }
+        }
+
+        // If no free variables, we have a unique solution
+        if free_vars.is_empty() {
+            let mut solution = vec![false; num_buttons];
+            for (row_idx, &pivot) in pivots.iter().enumerate() {
+                if let Some(col) = pivot {
+                    solution[col] = matrix[row_idx][num_buttons];
+                }
+            }
+            return solution.iter().filter(|&&x| x).count();
+        }
+
+        // Multiple solutions exist - try all combinations of free variables
+        let num_free = free_vars.len();
+        let mut min_presses = usize::MAX;
+
+        for mask in 0..(1 << num_free) {
+            let mut solution = vec![false; num_buttons];
+
+            // Set free variables according to mask
+            for (i, &free_var) in free_vars.iter().enumerate() {
+                solution[free_var] = (mask >> i) & 1 == 1;
+            }
+
+            // Calculate dependent variables (pivot columns)
+            for (row_idx, &pivot) in pivots.iter().enumerate() {
+                if let Some(col) = pivot {
+                    let mut val = matrix[row_idx][num_buttons];
+                    // XOR with contributions from free variables
+                    for (free_col, &is_set) in solution.iter().enumerate() {
+                        if is_set && matrix[row_idx][free_col] {
+                            val ^= true;
+                        }
+                    }
+                    solution[col] = val;
+                }
+            }
+
+            let presses = solution.iter().filter(|&&x| x).count();
+            min_presses = min_presses.min(presses);
+        }
+
+        min_presses
+    }
+}
+
+/// Parses a comma-separated list of indices from `list`, a slice of `line`, each of which must be
+/// below `len`.
+fn parse_indices(
+    line: &str,
+    list: &str,
+    expected: &str,
+    len: usize,
+) -> Result<Vec<usize>, ParseError> {
+    if list.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    list.split(',')
+        .map(|token| {
+            let token = token.trim();
+            let index = parse::parse_token(line, token, expected)?;
+            if index >= len {
+                return Err(ParseError::at(line, token, format!("an index below {len}")));
+            }
+            Ok(index)
+        })
+        .collect()
+}
+
+impl FromStr for Manual {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Parse goal from [.##.]
+        let goal_str = s
+            .strip_prefix('[')
+            .ok_or_else(|| ParseError::new(s, 1, "`[` to start the indicator lights"))?;
+        let goal_end = goal_str
+            .find(']')
+            .ok_or_else(|| ParseError::at_end(s, "`]` to end the indicator lights"))?;
+        let goal_str = &goal_str[..goal_end];
+
+        let mut goal = Vec::new();
+        for (i, c) in goal_str.char_indices() {
+            goal.push(match c {
+                '#' => true,
+                '.' => false,
+                _ => return Err(ParseError::at(s, &goal_str[i..], "`#` or `.`")),
+            });
+        }
+
+        // Parse wiring schematics from (1,3) (2) etc.
+        let mut wiring_schematics = Vec::new();
+        let mut rest = s[goal_end + 2..].trim_start();
+        while let Some(button) = rest.strip_prefix('(') {
+            let button_end = button
+                .find(')')
+                .ok_or_else(|| ParseError::at_end(s, "`)` to end the button"))?;
+            wiring_schematics.push(parse_indices(
+                s,
+                &button[..button_end],
+                "a light index",
+                goal.len(),
+            )?);
+            rest = button[button_end + 1..].trim_start();
+        }
+
+        // Parse joltage requirements from {3,5,4,7}
+        let after_brace = rest
+            .strip_prefix('{')
+            .ok_or_else(|| ParseError::at(s, rest, "`(` or `{`"))?;
+        let joltage_end = after_brace
+            .find('}')
+            .ok_or_else(|| ParseError::at_end(s, "`}` to end the joltage requirements"))?;
+        let joltage_str = &after_brace[..joltage_end];
+
+        let joltage_requirements: Vec<usize> = joltage_str
+            .split(',')
+            .map(|n| parse::parse_token(s, n.trim(), "a joltage requirement"))
+            .collect::<Result<_, _>>()?;
+        if joltage_requirements.len() != goal.len() {
+            return Err(ParseError::at(
+                s,
+                joltage_str,
+                format!("{} joltage requirements", goal.len()),
+            ));
+        }
+
+        let trailing = after_brace[joltage_end + 1..].trim();
+        if !trailing.is_empty() {
+            return Err(ParseError::at(s, trailing, "the end of the line"));
+        }
+
+        Ok(Manual {
+            goal,
+            wiring_schematics,
+            joltage_requirements,
+        })
+    }
+}
+
+/// [`Solution`] for day 10.
+#[derive(Debug, Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Manuals;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Manuals::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.min_presses().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.min_joltage_presses().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manual() -> Result<()> {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let manual: Manual = input.parse()?;
+
+        assert_eq!(manual.goal, vec![false, true, true, false]);
+        assert_eq!(manual.wiring_schematics.len(), 6);
+        assert_eq!(manual.wiring_schematics[0], vec![3]);
+        assert_eq!(manual.wiring_schematics[1], vec![1, 3]);
+        assert_eq!(manual.wiring_schematics[2], vec![2]);
+        assert_eq!(manual.wiring_schematics[3], vec![2, 3]);
+        assert_eq!(manual.wiring_schematics[4], vec![0, 2]);
+        assert_eq!(manual.wiring_schematics[5], vec![0, 1]);
+        assert_eq!(manual.joltage_requirements, vec![3, 5, 4, 7]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Manual::from_str("(3) {3}").unwrap_err();
+        assert_eq!(1, error.column);
+        assert_eq!("`[` to start the indicator lights", error.expected);
+
+        let error = Manual::from_str("[.##.] (3) (1,4) (2) {3,5,4,7}").unwrap_err();
+        assert_eq!(
+            "column 15: expected an index below 4\n  [.##.] (3) (1,4) (2) {3,5,4,7}\n                ^",
+            error.to_string()
+        );
+
+        let error = Manual::from_str("[.##.] (3) (1,3) (2) {3,5,4}").unwrap_err();
+        assert_eq!(23, error.column);
+        assert_eq!("4 joltage requirements", error.expected);
+
+        let Err(error) = Manuals::try_from(vec![
+            "[.#] (1) {0,1}".to_string(),
+            "[.#] (1) 0,1".to_string(),
+        ]) else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(
+            "line 2, column 10: expected `(` or `{`\n  [.#] (1) 0,1\n           ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn min_presses() -> Result<()> {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let manual: Manual = input.parse()?;
+
+        assert_eq!(Some(2), manual.min_presses());
+        assert_eq!(Some(10), manual.min_joltage_presses());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unreachable() -> Result<()> {
+        let manuals = Manuals(vec![
+            "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}".parse()?,
+            "[#.] (1) {1,2}".parse()?,
+        ]);
+
+        warnings::take();
+        assert_eq!(2, manuals.min_presses());
+        assert_eq!(
+            vec!["Skipping manual 2: goal is unreachable"],
+            warnings::take()
+        );
+
+        assert_eq!(10, manuals.min_joltage_presses());
+        assert_eq!(
+            vec!["Skipping manual 2: joltage requirements are unreachable"],
+            warnings::take()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        let manuals = Manuals::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(7, manuals.min_presses());
+        assert_eq!(33, manuals.min_joltage_presses());
+
+        Ok(())
+    }
+}
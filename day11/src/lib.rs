@@ -0,0 +1,236 @@
+//! Day 11: Reactor.
+//!
+//! Each device in the [`Reactor`] sends data to a list of other devices; count the paths data can
+//! take between them.
+
+use anyhow::{Result, bail};
+use common::{Answer, ParseError, Solution};
+use log::debug;
+use std::collections::{HashMap, HashSet};
+
+/// The puzzle input: a directed, acyclic graph of devices, one `name: out1 out2 ...` per line.
+#[derive(Debug, Default)]
+pub struct Reactor {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Reactor {
+    fn index(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+
+        let index = self.names.len();
+        self.names.push(name.to_string());
+        self.indices.insert(name.to_string(), index);
+        self.outputs.push(Vec::new());
+        index
+    }
+
+    /// Fails with the devices involved if any device's output eventually leads back to it.
+    fn check_acyclic(&self) -> Result<()> {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum State {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        fn visit(reactor: &Reactor, device: usize, states: &mut [State], path: &mut Vec<usize>) {
+            states[device] = State::InProgress;
+            path.push(device);
+
+            for &output in reactor.outputs[device].iter() {
+                match states[output] {
+                    State::Unvisited => {
+                        visit(reactor, output, states, path);
+                        if states[output] == State::InProgress {
+                            // A cycle was found further down; leave `path` ending at it
+                            return;
+                        }
+                    }
+                    State::InProgress => {
+                        path.push(output);
+                        return;
+                    }
+                    State::Done => {}
+                }
+            }
+
+            path.pop();
+            states[device] = State::Done;
+        }
+
+        let mut states = vec![State::Unvisited; self.names.len()];
+        for device in 0..self.names.len() {
+            if states[device] != State::Unvisited {
+                continue;
+            }
+
+            let mut path = Vec::new();
+            visit(self, device, &mut states, &mut path);
+
+            if let Some(&repeated) = path.last() {
+                let start = path.iter().position(|&d| d == repeated).unwrap();
+                let cycle: Vec<&str> = path[start..]
+                    .iter()
+                    .map(|&d| self.names[d].as_str())
+                    .collect();
+                bail!("Devices form a cycle: {}", cycle.join(" -> "));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The number of distinct paths from the device `from` to the device `to`.
+    pub fn path_count(&self, from: &str, to: &str) -> usize {
+        let (Some(&from), Some(&to)) = (self.indices.get(from), self.indices.get(to)) else {
+            return 0;
+        };
+
+        self.path_count_from(from, to, &mut vec![None; self.names.len()])
+    }
+
+    fn path_count_from(&self, device: usize, to: usize, cache: &mut [Option<usize>]) -> usize {
+        if device == to {
+            return 1;
+        }
+
+        if let Some(count) = cache[device] {
+            return count;
+        }
+
+        let count = self.outputs[device]
+            .iter()
+            .map(|&output| self.path_count_from(output, to, cache))
+            .sum();
+
+        cache[device] = Some(count);
+        count
+    }
+
+    /// The number of distinct paths from `from` to `to` which visit both `a` and `b`, in either
+    /// order.
+    pub fn path_count_through(&self, from: &str, to: &str, a: &str, b: &str) -> usize {
+        let via = |first, second| {
+            self.path_count(from, first)
+                * self.path_count(first, second)
+                * self.path_count(second, to)
+        };
+
+        let (a_first, b_first) = (via(a, b), via(b, a));
+        debug!("{a_first} paths visit {a} first, {b_first} visit {b} first");
+
+        a_first + b_first
+    }
+}
+
+impl TryFrom<Vec<String>> for Reactor {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self> {
+        let mut reactor = Reactor::default();
+        let mut defined = HashSet::new();
+
+        for (i, line) in value.iter().enumerate() {
+            let (name, outputs) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at_end(line, "`:`").on_line(i + 1))?;
+
+            let name = name.trim();
+            if name.is_empty() {
+                bail!(ParseError::new(line, 1, "a device name").on_line(i + 1));
+            }
+
+            let device = reactor.index(name);
+            if !defined.insert(device) {
+                bail!(ParseError::new(line, 1, "a device not already listed").on_line(i + 1));
+            }
+
+            for output in outputs.split_ascii_whitespace() {
+                let output = reactor.index(output);
+                reactor.outputs[device].push(output);
+            }
+        }
+
+        reactor.check_acyclic()?;
+
+        Ok(reactor)
+    }
+}
+
+/// [`Solution`] for day 11.
+#[derive(Debug, Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Reactor;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Reactor::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.path_count("you", "out").into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.path_count_through("svr", "out", "dac", "fft").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &str) -> Vec<String> {
+        input.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        let reactor = Reactor::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(5, reactor.path_count("you", "out"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_part2() -> Result<()> {
+        let reactor = Reactor::try_from(lines(
+            "svr: aaa bbb\n\
+             aaa: fft\n\
+             fft: ccc\n\
+             bbb: tty\n\
+             tty: ccc\n\
+             ccc: ddd eee\n\
+             ddd: hub\n\
+             hub: fff\n\
+             eee: dac\n\
+             dac: fff\n\
+             fff: ggg hhh\n\
+             ggg: out\n\
+             hhh: out",
+        ))?;
+
+        assert_eq!(8, reactor.path_count("svr", "out"));
+        assert_eq!(2, reactor.path_count_through("svr", "out", "dac", "fft"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cycle() {
+        let Err(error) = Reactor::try_from(lines("you: aaa\naaa: bbb out\nbbb: aaa")) else {
+            panic!("Expected a cycle to be detected");
+        };
+
+        assert_eq!("Devices form a cycle: aaa -> bbb -> aaa", error.to_string());
+    }
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+use day11::Day11;
+
+fn main() -> Result<()> {
+    common::main::<Day11>()
+}
@@ -0,0 +1,466 @@
+//! Day 12: Christmas Tree Farm.
+//!
+//! Presents come in a handful of [`Shape`]s; decide which [`Region`]s under the trees can fit all
+//! of the presents listed for them, which may be rotated and flipped but not overlap.
+
+use anyhow::{Result, bail};
+use common::parse::{self, ParseError};
+use common::{Answer, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+
+/// A present's shape, drawn as rows of `#` and `.`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    /// The `(x, y)` of each cell the shape covers.
+    cells: Vec<(usize, usize)>,
+}
+
+impl Shape {
+    /// The number of cells the shape covers.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The width and height of the smallest box containing the shape, in any orientation.
+    fn extent(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&(x, y)| x.max(y) + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Every distinct rotation and reflection of the shape, each shifted to touch both axes.
+    pub fn orientations(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut orientations: Vec<Vec<(usize, usize)>> = Vec::new();
+
+        let mut cells: Vec<(isize, isize)> = self
+            .cells
+            .iter()
+            .map(|&(x, y)| (x as isize, y as isize))
+            .collect();
+
+        for flip in 0..2 {
+            for _ in 0..4 {
+                // Rotate a quarter turn
+                cells = cells.iter().map(|&(x, y)| (-y, x)).collect();
+
+                let min_x = cells.iter().map(|c| c.0).min().unwrap_or(0);
+                let min_y = cells.iter().map(|c| c.1).min().unwrap_or(0);
+                let mut normalized: Vec<(usize, usize)> = cells
+                    .iter()
+                    .map(|&(x, y)| ((x - min_x) as usize, (y - min_y) as usize))
+                    .collect();
+                normalized.sort_unstable();
+
+                if !orientations.contains(&normalized) {
+                    orientations.push(normalized);
+                }
+            }
+
+            if flip == 0 {
+                cells = cells.iter().map(|&(x, y)| (-x, y)).collect();
+            }
+        }
+
+        orientations
+    }
+}
+
+/// The area under a tree, written as `WxH: ` followed by how many presents of each shape must fit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub counts: Vec<usize>,
+}
+
+impl Region {
+    /// Whether every present fits, rotating and flipping them as needed.
+    pub fn fits(&self, shapes: &[Shape]) -> bool {
+        let presents: usize = self.counts.iter().sum();
+        let area: usize = self
+            .counts
+            .iter()
+            .zip(shapes)
+            .map(|(count, shape)| count * shape.area())
+            .sum();
+
+        if area > self.width * self.height {
+            trace!("{self:?} is too small for {area} cells of presents");
+            return false;
+        }
+
+        // If each present gets a square to itself, they trivially fit without interlocking
+        let extent = shapes.iter().map(Shape::extent).max().unwrap_or(0);
+        if extent == 0 || (self.width / extent) * (self.height / extent) >= presents {
+            trace!("{self:?} fits every present side by side");
+            return true;
+        }
+
+        // Presents can be flipped, so the transposed region fits the same presents. Filling rows
+        // along the shorter side keeps the search's frontier small.
+        if self.width > self.height {
+            let transposed = Region {
+                width: self.height,
+                height: self.width,
+                counts: self.counts.clone(),
+            };
+            return transposed.fits(shapes);
+        }
+
+        debug!("Searching for a packing of {self:?}");
+        let orientations: Vec<Vec<Vec<(isize, isize)>>> =
+            shapes.iter().map(Self::anchored_orientations).collect();
+
+        let placements: Vec<Vec<Vec<usize>>> =
+            shapes.iter().map(|shape| self.placements(shape)).collect();
+
+        let mut search = Search {
+            width: self.width,
+            height: self.height,
+            orientations: &orientations,
+            placements: &placements,
+            remaining: self.counts.clone(),
+            occupied: vec![false; self.width * self.height],
+            skips: self.width * self.height - area,
+            reach: (extent - 1) * self.width + extent,
+            failed: HashSet::new(),
+        };
+        search.fill(0)
+    }
+
+    /// Every way of placing `shape` within the region, as the cells it would cover.
+    fn placements(&self, shape: &Shape) -> Vec<Vec<usize>> {
+        let mut placements = Vec::new();
+
+        for orientation in shape.orientations() {
+            let width = orientation.iter().map(|c| c.0 + 1).max().unwrap_or(0);
+            let height = orientation.iter().map(|c| c.1 + 1).max().unwrap_or(0);
+            if width > self.width || height > self.height {
+                continue;
+            }
+
+            for y in 0..=self.height - height {
+                for x in 0..=self.width - width {
+                    placements.push(
+                        orientation
+                            .iter()
+                            .map(|&(dx, dy)| (y + dy) * self.width + x + dx)
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        placements
+    }
+
+    /// Each orientation of `shape` as offsets from its first cell in reading order, so that
+    /// placing it at a cell covers that cell and none before it.
+    fn anchored_orientations(shape: &Shape) -> Vec<Vec<(isize, isize)>> {
+        shape
+            .orientations()
+            .into_iter()
+            .map(|mut cells| {
+                cells.sort_unstable_by_key(|&(x, y)| (y, x));
+                let (ax, ay) = cells[0];
+                cells
+                    .iter()
+                    .map(|&(x, y)| (x as isize - ax as isize, y as isize - ay as isize))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Backtracking state for packing presents into a region.
+///
+/// Cells are decided in reading order: the first undecided cell is either covered by a present
+/// anchored there, or left empty. Only as many cells may be left empty as the region has to spare,
+/// so the search gives up as soon as more cells than that can no longer be covered by any
+/// remaining present.
+///
+/// Presents anchored at a cell can only reach `reach` cells ahead of it, so whether the rest of the
+/// region can be filled depends only on those cells and the presents remaining. States which
+/// failed are remembered so that they aren't searched again.
+struct Search<'a> {
+    width: usize,
+    height: usize,
+    orientations: &'a [Vec<Vec<(isize, isize)>>],
+    placements: &'a [Vec<Vec<usize>>],
+    remaining: Vec<usize>,
+    occupied: Vec<bool>,
+    skips: usize,
+    reach: usize,
+    failed: HashSet<(usize, Vec<bool>, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    fn cells(&self, cell: usize, offsets: &[(isize, isize)]) -> Option<Vec<usize>> {
+        let (x, y) = ((cell % self.width) as isize, (cell / self.width) as isize);
+
+        offsets
+            .iter()
+            .map(|&(dx, dy)| {
+                let (x, y) = (x + dx, y + dy);
+                if x < 0 || y < 0 || x >= self.width as isize || y >= self.height as isize {
+                    return None;
+                }
+
+                let cell = y as usize * self.width + x as usize;
+                (!self.occupied[cell]).then_some(cell)
+            })
+            .collect()
+    }
+
+    /// The number of free cells which no remaining present could cover.
+    fn dead_cells(&self) -> usize {
+        let mut coverable = vec![false; self.occupied.len()];
+
+        for (shape, placements) in self.placements.iter().enumerate() {
+            if self.remaining[shape] == 0 {
+                continue;
+            }
+
+            for cells in placements {
+                if cells.iter().all(|&cell| !self.occupied[cell]) {
+                    cells.iter().for_each(|&cell| coverable[cell] = true);
+                }
+            }
+        }
+
+        self.occupied
+            .iter()
+            .zip(coverable)
+            .filter(|&(&occupied, coverable)| !occupied && !coverable)
+            .count()
+    }
+
+    fn fill(&mut self, from: usize) -> bool {
+        if self.remaining.iter().all(|&count| count == 0) {
+            return true;
+        }
+
+        let Some(cell) = (from..self.occupied.len()).find(|&cell| !self.occupied[cell]) else {
+            return false;
+        };
+
+        let window = self.occupied[cell..self.occupied.len().min(cell + self.reach)].to_vec();
+        let state = (cell, window, self.remaining.clone());
+        if self.failed.contains(&state) || self.dead_cells() > self.skips {
+            return false;
+        }
+
+        for shape in 0..self.remaining.len() {
+            if self.remaining[shape] == 0 {
+                continue;
+            }
+
+            for offsets in self.orientations[shape].iter() {
+                let Some(cells) = self.cells(cell, offsets) else {
+                    continue;
+                };
+
+                cells.iter().for_each(|&c| self.occupied[c] = true);
+                self.remaining[shape] -= 1;
+
+                if self.fill(cell + 1) {
+                    return true;
+                }
+
+                self.remaining[shape] += 1;
+                cells.iter().for_each(|&c| self.occupied[c] = false);
+            }
+        }
+
+        if self.skips > 0 {
+            self.skips -= 1;
+            self.occupied[cell] = true;
+
+            if self.fill(cell + 1) {
+                return true;
+            }
+
+            self.occupied[cell] = false;
+            self.skips += 1;
+        }
+
+        self.failed.insert(state);
+        false
+    }
+}
+
+/// The puzzle input: the present [`Shape`]s, followed by the [`Region`]s to fill.
+#[derive(Debug, Default)]
+pub struct Farm {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+impl Farm {
+    /// The number of regions which can fit all of their presents.
+    pub fn fitting_regions(&self) -> usize {
+        self.regions
+            .iter()
+            .filter(|region| region.fits(&self.shapes))
+            .count()
+    }
+}
+
+impl TryFrom<Vec<String>> for Farm {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self> {
+        let mut farm = Farm::default();
+        // The cells of the shape being read, and the index of its `N:` line
+        let mut shape: Option<(Vec<(usize, usize)>, usize)> = None;
+        let mut shape_row = 0;
+
+        let finish_shape = |farm: &mut Farm, (cells, header): (Vec<(usize, usize)>, usize)| {
+            if cells.is_empty() {
+                bail!(
+                    ParseError::new(&value[header], 1, "a shape with at least one `#`")
+                        .on_line(header + 1)
+                );
+            }
+            farm.shapes.push(Shape { cells });
+            Ok(())
+        };
+
+        for (i, line) in value.iter().enumerate() {
+            let on_line = |e: ParseError| e.on_line(i + 1);
+
+            if line.is_empty() {
+                if let Some(shape) = shape.take() {
+                    finish_shape(&mut farm, shape)?;
+                }
+                continue;
+            }
+
+            if let Some((cells, _)) = shape.as_mut() {
+                for (x, c) in line.chars().enumerate() {
+                    match c {
+                        '#' => cells.push((x, shape_row)),
+                        '.' => {}
+                        _ => bail!(on_line(ParseError::new(line, x + 1, "`#` or `.`"))),
+                    }
+                }
+                shape_row += 1;
+                continue;
+            }
+
+            let (label, counts) = line
+                .split_once(':')
+                .ok_or_else(|| on_line(ParseError::at_end(line, "`:`")))?;
+
+            if let Some((width, height)) = label.split_once('x') {
+                let counts = counts
+                    .split_ascii_whitespace()
+                    .map(|count| parse::parse_token(line, count, "a present count"))
+                    .collect::<Result<Vec<usize>, _>>()
+                    .map_err(on_line)?;
+                if counts.len() != farm.shapes.len() {
+                    bail!(on_line(ParseError::at_end(
+                        line,
+                        format!("{} present counts", farm.shapes.len())
+                    )));
+                }
+
+                farm.regions.push(Region {
+                    width: parse::parse_token(line, width, "a width").map_err(on_line)?,
+                    height: parse::parse_token(line, height, "a height").map_err(on_line)?,
+                    counts,
+                });
+            } else {
+                if !farm.regions.is_empty() {
+                    bail!(on_line(ParseError::new(line, 1, "a region")));
+                }
+
+                let index: usize = parse::parse_token(line, label, "a shape index or region size")
+                    .map_err(on_line)?;
+                if index != farm.shapes.len() {
+                    bail!(on_line(ParseError::new(
+                        line,
+                        1,
+                        format!("shape {}", farm.shapes.len())
+                    )));
+                }
+
+                shape = Some((Vec::new(), i));
+                shape_row = 0;
+            }
+        }
+
+        if let Some(shape) = shape {
+            finish_shape(&mut farm, shape)?;
+        }
+
+        Ok(farm)
+    }
+}
+
+/// [`Solution`] for day 12.
+#[derive(Debug, Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Farm;
+
+    fn parse(&self, input: Vec<String>) -> Result<Self::Input> {
+        Farm::try_from(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.fitting_regions().into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        // The final day has no second puzzle
+        Ok("Merry Christmas!".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientations() -> Result<()> {
+        let farm = Farm::try_from(aoc_util::init_test()?)?;
+
+        assert_eq!(6, farm.shapes.len());
+        assert_eq!(7, farm.shapes[0].area());
+        assert_eq!(8, farm.shapes[0].orientations().len());
+        assert_eq!(2, farm.shapes[5].orientations().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        let farm = Farm::try_from(aoc_util::init_test()?)?;
+
+        let fits: Vec<bool> = farm.regions.iter().map(|r| r.fits(&farm.shapes)).collect();
+        assert_eq!(vec![true, true, false], fits);
+        assert_eq!(2, farm.fitting_regions());
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_shape() {
+        let input = ["0:", "#.", "", "1:", "..", "..", "", "2x2: 1 1"];
+        let Err(error) = Farm::try_from(input.map(String::from).to_vec()) else {
+            panic!("Expected an empty shape to be rejected");
+        };
+
+        assert_eq!(
+            "line 4, column 1: expected a shape with at least one `#`\n  1:\n  ^",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+use day12::Day12;
+
+fn main() -> Result<()> {
+    common::main::<Day12>()
+}